@@ -3,11 +3,7 @@ import { dirname, resolve } from "node:path";
 import { fileURLToPath } from "node:url";
 import chalk from "chalk";
 import ora from "ora";
-import {
-    loadCometConfig,
-    updateCometConfig,
-    updateTauriConfig,
-} from "./utils/configManager";
+import { loadCometConfig, updateTauriConfig } from "./utils/configManager";
 import { updateThemes } from "./utils/themeManager";
 import { updateTitles } from "./utils/updateTitles";
 
@@ -64,7 +60,6 @@ async function buildForBrand(brandName: string): Promise<void> {
     const configSpinner = ora("Updating configuration files").start();
     try {
         updateTauriConfig(brandConfig, tauriConfigPath);
-        updateCometConfig(brandConfig);
         updateTitles(brandConfig);
         updateThemes(brandName);
         configSpinner.succeed(
@@ -94,7 +89,6 @@ async function buildForBrand(brandName: string): Promise<void> {
             try {
                 const defaultConfig = config.brands.default;
                 updateTauriConfig(defaultConfig, tauriConfigPath);
-                updateCometConfig(defaultConfig);
                 updateTitles(defaultConfig);
                 updateThemes("default");
                 resetSpinner.succeed(
@@ -1,7 +1,6 @@
 export type NotificationConfig = {
     title: string;
     disconnect: {
-        title: string;
         body: string;
     };
 };
@@ -9,10 +8,19 @@ export type NotificationConfig = {
 export type BrandConfig = {
     productName: string;
     iconPath: string;
+    executor: string;
+    installUrl: string;
+    dylibPath: string;
+    dataFolder: string;
+    keyFile: string;
+    /** Absent for executors whose keys Comet can't validate */
+    keyValidationUrl?: string;
+    /** Absent until releases publish a manifest for this brand */
+    updateManifest?: string;
+    notifications: NotificationConfig;
 };
 
 export type CometConfig = {
-    notifications: NotificationConfig;
     brands: Record<string, BrandConfig>;
 };
 
@@ -25,10 +25,3 @@ export function updateTauriConfig(
 
     writeFileSync(configPath, JSON.stringify(tauriConfig, null, 4));
 }
-
-export function updateCometConfig(brandConfig: BrandConfig): void {
-    const sourceConfigPath = resolve(__dirname, "../config.json");
-    const cometConfig = loadCometConfig();
-    cometConfig.notifications.title = brandConfig.productName;
-    writeFileSync(sourceConfigPath, JSON.stringify(cometConfig, null, 4));
-}
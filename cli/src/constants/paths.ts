@@ -7,3 +7,12 @@ export const SCRIPTS_DIR = path.join(
     "CLI",
     "Scripts",
 );
+
+export const COMET_DATA_DIR = path.join(
+    process.env.HOME || "",
+    "Library",
+    "Application Support",
+    "com.comet.dev",
+);
+export const CONTROL_SOCKET = path.join(COMET_DATA_DIR, "control", "comet.sock");
+export const CONTROL_TOKEN = path.join(COMET_DATA_DIR, "control", "control.token");
@@ -1,2 +0,0 @@
-export const START_PORT = 6969 as const;
-export const END_PORT = 7069 as const;
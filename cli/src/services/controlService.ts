@@ -0,0 +1,49 @@
+import fs from "node:fs/promises";
+import net from "node:net";
+import { CONTROL_SOCKET, CONTROL_TOKEN } from "../constants/paths.js";
+import type { ControlRequest, ControlResponse } from "../types/control.js";
+
+/**
+ * Sends a request to the running Comet app over its local control socket
+ * @param request The command to run
+ * @returns The command's result
+ * @throws If Comet is not running, the token is rejected or the command fails
+ */
+export async function sendRequest<T>(request: ControlRequest): Promise<T> {
+    const token = await fs
+        .readFile(CONTROL_TOKEN, "utf-8")
+        .then((content) => content.trim())
+        .catch(() => {
+            throw new Error("Comet is not running. Open Comet and try again.");
+        });
+
+    const reply = await new Promise<string>((resolve, reject) => {
+        const socket = net.createConnection(CONTROL_SOCKET);
+        let buffer = "";
+
+        socket.on("connect", () => {
+            socket.write(`${JSON.stringify({ token, ...request })}\n`);
+        });
+        socket.on("data", (chunk) => {
+            buffer += chunk.toString();
+            const newline = buffer.indexOf("\n");
+            if (newline !== -1) {
+                socket.end();
+                resolve(buffer.slice(0, newline));
+            }
+        });
+        socket.on("error", () => {
+            reject(new Error("Comet is not running. Open Comet and try again."));
+        });
+        socket.on("close", () => {
+            reject(new Error("Comet closed the connection"));
+        });
+    });
+
+    const response = JSON.parse(reply) as ControlResponse<T>;
+    if (!response.ok) {
+        throw new Error(response.error ?? "Unknown error");
+    }
+
+    return response.result as T;
+}
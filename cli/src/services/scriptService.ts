@@ -1,32 +1,13 @@
 import fs from "node:fs/promises";
 import path from "node:path";
 import { SCRIPTS_DIR } from "../constants/paths.js";
-import { END_PORT, START_PORT } from "../constants/ports.js";
+import type { ExecutionResult } from "../types/control.js";
 import type { ExecutionResponse, Script } from "../types/script.js";
 import { ensureScriptsDirExists } from "../utils/file.js";
+import { sendRequest } from "./controlService.js";
 
 /**
- * Attempts to find the port where the server is running by checking ports in range
- * @returns Port number if server is found, null otherwise
- */
-async function findServerPort(): Promise<number | null> {
-    for (let port = START_PORT; port <= END_PORT; port++) {
-        try {
-            const res = await fetch(`http://127.0.0.1:${port}/secret`, {
-                method: "GET",
-            });
-
-            if (res.ok && (await res.text()) === "0xdeadbeef") {
-                return port;
-            }
-        } catch {}
-    }
-
-    return null;
-}
-
-/**
- * Sends a script to the server for execution
+ * Sends a script to Comet, which runs it on the selected executor
  * @param scriptContent The Lua script content to execute
  * @returns Response containing success status and output/error message
  */
@@ -34,35 +15,27 @@ export async function executeScript(
     scriptContent: string,
 ): Promise<ExecutionResponse> {
     try {
-        const serverPort = await findServerPort();
-
-        if (!serverPort) {
-            return {
-                success: false,
-                error: `Could not locate server on ports ${START_PORT}-${END_PORT}. Is Roblox running?`,
-            };
-        }
-
-        const response = await fetch(`http://127.0.0.1:${serverPort}/execute`, {
-            method: "POST",
-            headers: {
-                "Content-Type": "text/plain",
-            },
-            body: scriptContent,
+        const result = await sendRequest<ExecutionResult>({
+            command: "execute",
+            script: scriptContent,
         });
 
-        if (!response.ok) {
-            const errorText = await response.text();
+        if (!result.success) {
+            const status =
+                result.status !== null
+                    ? `HTTP ${result.status}`
+                    : "Execution failed";
             return {
                 success: false,
-                error: `HTTP ${response.status}: ${errorText}`,
+                error: result.response
+                    ? `${status}: ${result.response}`
+                    : status,
             };
         }
 
-        const output = await response.text();
         return {
             success: true,
-            output,
+            output: result.response ?? "",
         };
     } catch (error) {
         return {
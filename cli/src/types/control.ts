@@ -0,0 +1,32 @@
+export type ExecutorName = "Hydrogen" | "MacSploit";
+
+export type ControlRequest =
+    | { command: "status" }
+    | { command: "reconnect" }
+    | { command: "execute"; script: string }
+    | { command: "list_workspaces" }
+    | { command: "list_tabs"; workspace_id?: string }
+    | { command: "history" };
+
+export interface ControlResponse<T> {
+    ok: boolean;
+    result?: T;
+    error?: string;
+}
+
+export interface ConnectionStatus {
+    is_connected: boolean;
+    port: number | null;
+    current_port: number;
+    is_connecting: boolean;
+    api_type: ExecutorName;
+}
+
+export interface ExecutionResult {
+    success: boolean;
+    status: number | null;
+    response: string | null;
+    port: number;
+    api_type: ExecutorName;
+    elapsed_ms: number;
+}
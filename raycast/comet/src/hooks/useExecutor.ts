@@ -41,7 +41,7 @@ export function useExecutor() {
         });
 
         try {
-            await executeScript(state.serverPort, scriptContent);
+            await executeScript(scriptContent);
             toast.style = Toast.Style.Success;
             toast.title = "Script Executed";
             toast.message = "Check Roblox for results";
@@ -1,59 +1,122 @@
-export const START_PORT = 6969 as const;
-export const END_PORT = 7069 as const;
+import fs from "node:fs/promises";
+import net from "node:net";
+import os from "node:os";
+import path from "node:path";
+
+const COMET_DATA_DIR = path.join(
+    os.homedir(),
+    "Library",
+    "Application Support",
+    "com.comet.dev",
+);
+const CONTROL_SOCKET = path.join(COMET_DATA_DIR, "control", "comet.sock");
+const CONTROL_TOKEN = path.join(COMET_DATA_DIR, "control", "control.token");
+const NOT_RUNNING = "Comet is not running. Open Comet and try again.";
 
 export interface ExecutorState {
     serverPort: number | null;
     isConnecting: boolean;
 }
 
+interface ConnectionStatus {
+    is_connected: boolean;
+    port: number | null;
+}
+
+interface ExecutionResult {
+    success: boolean;
+    status: number | null;
+    response: string | null;
+}
+
 /**
- * Scans ports to find running Executor server
- * @returns Port number if server found, null otherwise
+ * Sends a request to the running Comet app over its local control socket
+ * @param request Command name and arguments
+ * @returns The command's result
  */
-export async function findExecutorServer(): Promise<number | null> {
-    for (let port = START_PORT; port <= END_PORT; port++) {
-        try {
-            const res = await fetch(`http://127.0.0.1:${port}/secret`, {
-                method: "GET",
-            });
+async function sendRequest<T>(
+    request: { command: string } & Record<string, unknown>,
+): Promise<T> {
+    const token = await fs
+        .readFile(CONTROL_TOKEN, "utf-8")
+        .then((content) => content.trim())
+        .catch(() => {
+            throw new Error(NOT_RUNNING);
+        });
 
-            if (res.ok && (await res.text()) === "0xdeadbeef") {
-                return port;
+    const reply = await new Promise<string>((resolve, reject) => {
+        const socket = net.createConnection(CONTROL_SOCKET);
+        let buffer = "";
+
+        socket.on("connect", () => {
+            socket.write(`${JSON.stringify({ token, ...request })}\n`);
+        });
+        socket.on("data", (chunk) => {
+            buffer += chunk.toString();
+            const newline = buffer.indexOf("\n");
+            if (newline !== -1) {
+                socket.end();
+                resolve(buffer.slice(0, newline));
             }
-        } catch (_error) {}
+        });
+        socket.on("error", () => reject(new Error(NOT_RUNNING)));
+        socket.on("close", () =>
+            reject(new Error("Comet closed the connection")),
+        );
+    });
+
+    const response = JSON.parse(reply) as {
+        ok: boolean;
+        result?: T;
+        error?: string;
+    };
+    if (!response.ok) {
+        throw new Error(response.error ?? "Unknown error");
     }
 
-    return null;
+    return response.result as T;
 }
 
 /**
- * Executes Lua script
- * @param serverPort Port number of Executor server
- * @param scriptContent Lua script content to execute
- * @returns Server response text
+ * Asks Comet which executor port it is connected to, retrying the connection if needed
+ * @returns Port number if Comet is connected, null otherwise
  */
-export async function executeScript(
-    serverPort: number,
-    scriptContent: string,
-): Promise<string> {
-    if (!serverPort) {
-        throw new Error(
-            `Could not locate Executor server on ports ${START_PORT}-${END_PORT}`,
-        );
+export async function findExecutorServer(): Promise<number | null> {
+    try {
+        let status = await sendRequest<ConnectionStatus>({
+            command: "status",
+        });
+
+        if (!status.is_connected) {
+            status = await sendRequest<ConnectionStatus>({
+                command: "reconnect",
+            });
+        }
+
+        return status.is_connected ? status.port : null;
+    } catch (_error) {
+        return null;
     }
+}
 
-    const response = await fetch(`http://127.0.0.1:${serverPort}/execute`, {
-        method: "POST",
-        headers: {
-            "Content-Type": "text/plain",
-        },
-        body: scriptContent,
+/**
+ * Executes Lua script through Comet on the user's selected executor
+ * @param scriptContent Lua script content to execute
+ * @returns Executor response text
+ */
+export async function executeScript(scriptContent: string): Promise<string> {
+    const result = await sendRequest<ExecutionResult>({
+        command: "execute",
+        script: scriptContent,
     });
 
-    if (!response.ok) {
-        const errorText = await response.text();
-        throw new Error(`HTTP ${response.status}: ${errorText}`);
+    if (!result.success) {
+        const status =
+            result.status !== null ? `HTTP ${result.status}` : "Execution failed";
+        throw new Error(
+            result.response ? `${status}: ${result.response}` : status,
+        );
     }
 
-    return await response.text();
+    return result.response ?? "";
 }
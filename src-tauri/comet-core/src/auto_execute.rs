@@ -0,0 +1,257 @@
+use crate::paths;
+use crate::settings;
+use crate::ApiType;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+const VALID_EXTENSIONS: [&str; 3] = [".lua", ".luau", ".txt"];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AutoExecuteFile {
+    pub name: String,
+    pub content: String,
+    pub path: String,
+}
+
+/// The folder `api_type` runs scripts from on launch.
+pub fn get_auto_execute_dir(api_type: ApiType) -> Result<PathBuf, String> {
+    let home = paths::home_dir()?;
+    Ok(api_type.backend().auto_execute_dir(&home))
+}
+
+fn get_scripts_dir() -> PathBuf {
+    let mut app_dir = paths::app_data_dir();
+    app_dir.push("scripts");
+    fs::create_dir_all(&app_dir).expect("Failed to create directory");
+    app_dir
+}
+
+fn read_file_content(path: &Path) -> Result<String, String> {
+    let mut file = File::open(path).map_err(|e| e.to_string())?;
+    let mut content = Vec::new();
+    file.read_to_end(&mut content).map_err(|e| e.to_string())?;
+
+    let content_clone = content.clone();
+    String::from_utf8(content)
+        .or_else::<String, _>(|_| Ok(content_clone.iter().map(|&b| b as char).collect::<String>()))
+        .map_err(|e| e.to_string())
+}
+
+fn is_valid_script_file(path: &Path) -> bool {
+    if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+        if name.starts_with(".") || name == ".DS_Store" {
+            return false;
+        }
+        return VALID_EXTENSIONS.iter().any(|ext| name.ends_with(ext));
+    }
+    false
+}
+
+fn ensure_valid_extension(name: &str) -> String {
+    if VALID_EXTENSIONS.iter().any(|ext| name.ends_with(ext)) {
+        name.to_string()
+    } else {
+        format!("{}.lua", name)
+    }
+}
+
+fn get_auto_execute_state() -> Result<bool, String> {
+    Ok(settings::load()?.auto_execute.enabled)
+}
+
+fn set_auto_execute_state(enabled: bool) -> Result<(), String> {
+    settings::update(|settings| settings.auto_execute.enabled = enabled)?;
+    Ok(())
+}
+
+/// Lists the scripts stored in Comet's own auto-execute folder.
+pub fn get_auto_execute_files() -> Result<Vec<AutoExecuteFile>, String> {
+    let scripts_dir = get_scripts_dir();
+
+    if !scripts_dir.exists() {
+        fs::create_dir_all(&scripts_dir).map_err(|e| e.to_string())?;
+    }
+
+    let entries = fs::read_dir(&scripts_dir).map_err(|e| e.to_string())?;
+    let mut files = Vec::new();
+
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+
+        if path.is_file() && is_valid_script_file(&path) {
+            if let Ok(content) = read_file_content(&path) {
+                files.push(AutoExecuteFile {
+                    name: path
+                        .file_name()
+                        .ok_or("Invalid filename")?
+                        .to_string_lossy()
+                        .into_owned(),
+                    content,
+                    path: path.to_string_lossy().into_owned(),
+                });
+            }
+        }
+    }
+    files.sort_by_key(|a| a.name.to_lowercase());
+
+    Ok(files)
+}
+
+/// Saves a script and, while auto-execute is enabled, mirrors it into the executor's folder.
+pub fn save_auto_execute_file(name: &str, content: &str, api_type: ApiType) -> Result<(), String> {
+    let file_name = ensure_valid_extension(name);
+    let scripts_dir = get_scripts_dir();
+    let file_path = scripts_dir.join(&file_name);
+
+    fs::write(&file_path, content).map_err(|e| e.to_string())?;
+
+    if get_auto_execute_state()? {
+        let auto_execute_dir = get_auto_execute_dir(api_type)?;
+        if !auto_execute_dir.exists() {
+            fs::create_dir_all(&auto_execute_dir).map_err(|e| e.to_string())?;
+        }
+        let auto_execute_path = auto_execute_dir.join(&file_name);
+        fs::copy(&file_path, &auto_execute_path).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+pub fn delete_auto_execute_file(name: &str, api_type: ApiType) -> Result<(), String> {
+    let scripts_dir = get_scripts_dir();
+    let file_path = scripts_dir.join(name);
+
+    if file_path.exists() {
+        fs::remove_file(&file_path).map_err(|e| e.to_string())?;
+    }
+
+    if get_auto_execute_state()? {
+        let auto_execute_dir = get_auto_execute_dir(api_type)?;
+        let auto_execute_path = auto_execute_dir.join(name);
+        if auto_execute_path.exists() {
+            fs::remove_file(&auto_execute_path).map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn rename_auto_execute_file(
+    old_name: &str,
+    new_name: &str,
+    api_type: ApiType,
+) -> Result<(), String> {
+    let new_file_name = ensure_valid_extension(new_name);
+    let scripts_dir = get_scripts_dir();
+    let old_path = scripts_dir.join(old_name);
+    let new_path = scripts_dir.join(&new_file_name);
+
+    if !old_path.exists() {
+        return Err("Source file does not exist".to_string());
+    }
+
+    if new_path.exists() {
+        return Err("A file with that name already exists".to_string());
+    }
+
+    fs::rename(&old_path, &new_path).map_err(|e| e.to_string())?;
+
+    if get_auto_execute_state()? {
+        let auto_execute_dir = get_auto_execute_dir(api_type)?;
+        let old_auto_execute_path = auto_execute_dir.join(old_name);
+        let new_auto_execute_path = auto_execute_dir.join(&new_file_name);
+
+        if old_auto_execute_path.exists() {
+            fs::rename(&old_auto_execute_path, &new_auto_execute_path)
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn is_auto_execute_enabled() -> Result<bool, String> {
+    get_auto_execute_state()
+}
+
+/// Flips auto-execute for `api_type` by syncing or clearing its folder. Returns the new state.
+pub fn toggle_auto_execute(api_type: ApiType) -> Result<bool, String> {
+    let auto_execute_dir = get_auto_execute_dir(api_type)?;
+    let scripts_dir = get_scripts_dir();
+
+    let currently_enabled = get_auto_execute_state()?;
+
+    if currently_enabled {
+        if auto_execute_dir.exists() {
+            let entries = fs::read_dir(&auto_execute_dir).map_err(|e| e.to_string())?;
+            for entry in entries {
+                let entry = entry.map_err(|e| e.to_string())?;
+                let path = entry.path();
+                if path.is_file() && is_valid_script_file(&path) {
+                    fs::remove_file(&path).map_err(|e| e.to_string())?;
+                }
+            }
+        }
+    } else {
+        if !auto_execute_dir.exists() {
+            fs::create_dir_all(&auto_execute_dir).map_err(|e| e.to_string())?;
+        }
+
+        let entries = fs::read_dir(&scripts_dir).map_err(|e| e.to_string())?;
+        for entry in entries {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let path = entry.path();
+
+            if path.is_file() && is_valid_script_file(&path) {
+                let file_name = path
+                    .file_name()
+                    .ok_or("Invalid filename")?
+                    .to_string_lossy()
+                    .into_owned();
+                let auto_execute_path = auto_execute_dir.join(&file_name);
+                fs::copy(&path, &auto_execute_path).map_err(|e| e.to_string())?;
+            }
+        }
+    }
+
+    let new_state = !currently_enabled;
+    set_auto_execute_state(new_state)?;
+
+    Ok(new_state)
+}
+
+/// Copies new or changed scripts into `api_type`'s folder while auto-execute is enabled.
+/// Returns how many files were copied.
+pub fn sync_auto_execute(api_type: ApiType) -> Result<usize, String> {
+    if !get_auto_execute_state()? {
+        return Ok(0);
+    }
+
+    let auto_execute_dir = get_auto_execute_dir(api_type)?;
+    let scripts_dir = get_scripts_dir();
+    if !auto_execute_dir.exists() {
+        fs::create_dir_all(&auto_execute_dir).map_err(|e| e.to_string())?;
+    }
+
+    let mut copied = 0;
+    let entries = fs::read_dir(&scripts_dir).map_err(|e| e.to_string())?;
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+
+        if path.is_file() && is_valid_script_file(&path) {
+            let auto_execute_path = auto_execute_dir.join(entry.file_name());
+            let content = fs::read(&path).map_err(|e| e.to_string())?;
+            if fs::read(&auto_execute_path).ok().as_ref() != Some(&content) {
+                fs::write(&auto_execute_path, content).map_err(|e| e.to_string())?;
+                copied += 1;
+            }
+        }
+    }
+
+    Ok(copied)
+}
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tokio::sync::broadcast;
+
+use crate::ApiType;
+
+mod hydrogen;
+mod macsploit;
+
+pub use hydrogen::Hydrogen;
+pub use macsploit::{MacSploit, SessionPool};
+
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+
+const OUTPUT_CAPACITY: usize = 256;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExecuteError {
+    #[error("Failed to connect to server: {0}")]
+    ConnectionError(String),
+    #[error("Failed to execute script: {0}")]
+    ExecutionError(String),
+}
+
+impl serde::Serialize for ExecuteError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExecutorResponse {
+    pub success: bool,
+    pub status: Option<u16>,
+    pub body: Option<String>,
+    pub execution_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputKind {
+    Print,
+    Error,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExecutorOutput {
+    pub port: u16,
+    pub kind: OutputKind,
+    pub message: String,
+    pub execution_id: Option<String>,
+    pub script_preview: Option<String>,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointConfig {
+    pub host: String,
+    pub min_port: u16,
+    pub max_port: u16,
+    pub secret_path: Option<String>,
+    pub secret_token: Option<String>,
+}
+
+impl EndpointConfig {
+    pub fn port_range(&self) -> (u16, u16) {
+        (self.min_port, self.max_port)
+    }
+
+    pub fn url(&self, port: u16, path: &str) -> String {
+        format!("http://{}:{}{}", self.host, port, path)
+    }
+}
+
+#[derive(Debug)]
+pub struct Transport {
+    pub client: Client,
+    pub sessions: SessionPool,
+    endpoints: RwLock<HashMap<ApiType, EndpointConfig>>,
+    output: broadcast::Sender<ExecutorOutput>,
+}
+
+impl Transport {
+    pub fn new(client: Client, endpoints: HashMap<ApiType, EndpointConfig>) -> Self {
+        let (output, _) = broadcast::channel(OUTPUT_CAPACITY);
+        Self {
+            client,
+            sessions: SessionPool::new(output.clone()),
+            endpoints: RwLock::new(endpoints),
+            output,
+        }
+    }
+
+    pub fn endpoint(&self, api_type: ApiType) -> EndpointConfig {
+        self.endpoints
+            .read()
+            .unwrap()
+            .get(&api_type)
+            .cloned()
+            .unwrap_or_else(|| api_type.backend().default_endpoint())
+    }
+
+    // Open MacSploit sessions may point at a host or port that is no longer configured.
+    pub async fn set_endpoints(&self, endpoints: HashMap<ApiType, EndpointConfig>) {
+        *self.endpoints.write().unwrap() = endpoints;
+        self.sessions.clear().await;
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ExecutorOutput> {
+        self.output.subscribe()
+    }
+}
+
+#[async_trait]
+pub trait ExecutorBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn api_type(&self) -> ApiType;
+
+    fn default_endpoint(&self) -> EndpointConfig;
+
+    async fn handshake(&self, transport: &Transport, port: u16) -> bool;
+
+    async fn send(
+        &self,
+        transport: &Transport,
+        port: u16,
+        script: &str,
+    ) -> Result<ExecutorResponse, ExecuteError>;
+
+    fn auto_execute_dir(&self, home: &Path) -> PathBuf;
+
+    /// Files the executor places in `Roblox.app/Contents/MacOS` once installed.
+    fn roblox_files(&self) -> &'static [&'static str];
+
+    /// The executor's own app bundle in `/Applications`, if it ships one.
+    fn app_bundle(&self) -> Option<&'static str>;
+}
+
+impl ApiType {
+    pub const ALL: [ApiType; 2] = [ApiType::Hydrogen, ApiType::MacSploit];
+
+    pub fn backend(&self) -> &'static dyn ExecutorBackend {
+        match self {
+            ApiType::Hydrogen => &Hydrogen,
+            ApiType::MacSploit => &MacSploit,
+        }
+    }
+}
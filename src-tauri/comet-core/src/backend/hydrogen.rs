@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+use super::{
+    EndpointConfig, ExecuteError, ExecutorBackend, ExecutorResponse, Transport, DEFAULT_HOST,
+};
+use crate::ApiType;
+
+const SECRET_PATH: &str = "/secret";
+const SECRET_TOKEN: &str = "0xdeadbeef";
+
+pub struct Hydrogen;
+
+#[async_trait]
+impl ExecutorBackend for Hydrogen {
+    fn name(&self) -> &'static str {
+        "Hydrogen"
+    }
+
+    fn api_type(&self) -> ApiType {
+        ApiType::Hydrogen
+    }
+
+    fn default_endpoint(&self) -> EndpointConfig {
+        EndpointConfig {
+            host: DEFAULT_HOST.to_string(),
+            min_port: 6969,
+            max_port: 7069,
+            secret_path: Some(SECRET_PATH.to_string()),
+            secret_token: Some(SECRET_TOKEN.to_string()),
+        }
+    }
+
+    async fn handshake(&self, transport: &Transport, port: u16) -> bool {
+        let endpoint = transport.endpoint(ApiType::Hydrogen);
+        let path = endpoint.secret_path.as_deref().unwrap_or(SECRET_PATH);
+        let token = endpoint.secret_token.as_deref().unwrap_or(SECRET_TOKEN);
+        match transport.client.get(endpoint.url(port, path)).send().await {
+            Ok(response) => response
+                .text()
+                .await
+                .map(|text| text == token)
+                .unwrap_or(false),
+            Err(_) => false,
+        }
+    }
+
+    async fn send(
+        &self,
+        transport: &Transport,
+        port: u16,
+        script: &str,
+    ) -> Result<ExecutorResponse, ExecuteError> {
+        let url = transport.endpoint(ApiType::Hydrogen).url(port, "/execute");
+        let response = transport
+            .client
+            .post(url)
+            .header("Content-Type", "text/plain")
+            .body(script.to_string())
+            .send()
+            .await
+            .map_err(|e| ExecuteError::ConnectionError(e.to_string()))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| ExecuteError::ExecutionError(e.to_string()))?;
+
+        Ok(ExecutorResponse {
+            success: status.is_success(),
+            status: Some(status.as_u16()),
+            body: Some(body),
+            execution_id: None,
+        })
+    }
+
+    fn auto_execute_dir(&self, home: &Path) -> PathBuf {
+        home.join("Hydrogen/autoexecute")
+    }
+
+    fn roblox_files(&self) -> &'static [&'static str] {
+        &["RobloxPlayer.copy"]
+    }
+
+    fn app_bundle(&self) -> Option<&'static str> {
+        Some("Hydrogen.app")
+    }
+}
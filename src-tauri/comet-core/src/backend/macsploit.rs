@@ -0,0 +1,332 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+use tokio::sync::{broadcast, Mutex as AsyncMutex};
+use tokio::time::timeout;
+
+use super::{
+    EndpointConfig, ExecuteError, ExecutorBackend, ExecutorOutput, ExecutorResponse, OutputKind,
+    Transport, DEFAULT_HOST,
+};
+use crate::ApiType;
+
+const IPC_EXECUTE: u8 = 0;
+const MESSAGE_PRINT: u8 = 1;
+const MESSAGE_ERROR: u8 = 2;
+const HEADER_LEN: usize = 16;
+const MAX_MESSAGE_LEN: u64 = 16 * 1024 * 1024;
+const PREVIEW_LEN: usize = 80;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+// How long a session must stay quiet before a new script's output can be told apart from the last one's.
+const OUTPUT_SETTLE: Duration = Duration::from_millis(500);
+
+pub struct MacSploit;
+
+#[derive(Debug, Clone)]
+struct Execution {
+    id: String,
+    preview: String,
+}
+
+// MacSploit frames carry no execution id, so output is only attributed while a single script can
+// have produced it.
+#[derive(Debug, Default)]
+struct Attribution {
+    execution: Option<Execution>,
+    last_activity: Option<Instant>,
+}
+
+impl Attribution {
+    fn start(&mut self, execution: Execution) {
+        let settled = self
+            .last_activity
+            .is_none_or(|at| at.elapsed() >= OUTPUT_SETTLE);
+        self.execution = settled.then_some(execution);
+        self.last_activity = Some(Instant::now());
+    }
+
+    fn frame(&mut self) -> Option<Execution> {
+        self.last_activity = Some(Instant::now());
+        self.execution.clone()
+    }
+}
+
+#[derive(Debug)]
+struct Session {
+    writer: AsyncMutex<OwnedWriteHalf>,
+    alive: AtomicBool,
+    current: Mutex<Attribution>,
+}
+
+#[derive(Debug)]
+pub struct SessionPool {
+    sessions: AsyncMutex<HashMap<u16, Arc<Session>>>,
+    output: broadcast::Sender<ExecutorOutput>,
+}
+
+fn encode_frame(kind: u8, payload: &[u8]) -> Vec<u8> {
+    let mut buffer = vec![0u8; HEADER_LEN + payload.len()];
+    buffer[0] = kind;
+    buffer[8..16].copy_from_slice(&(payload.len() as u64).to_le_bytes());
+    buffer[HEADER_LEN..].copy_from_slice(payload);
+    buffer
+}
+
+// The kind and payload length of a frame, from its 16-byte header.
+fn decode_header(header: &[u8; HEADER_LEN]) -> (u8, u64) {
+    let mut length = [0u8; 8];
+    length.copy_from_slice(&header[8..16]);
+    (header[0], u64::from_le_bytes(length))
+}
+
+fn script_preview(script: &str) -> String {
+    let first_line = script
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    first_line.chars().take(PREVIEW_LEN).collect()
+}
+
+async fn connect(host: &str, port: u16) -> Result<TcpStream, String> {
+    timeout(CONNECT_TIMEOUT, TcpStream::connect((host, port)))
+        .await
+        .map_err(|_| format!("Timed out connecting to port {}", port))?
+        .map_err(|e| e.to_string())
+}
+
+impl Session {
+    fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
+    async fn execute(&self, script: &str) -> Result<String, String> {
+        let id = uuid::Uuid::new_v4().to_string();
+        self.current.lock().unwrap().start(Execution {
+            id: id.clone(),
+            preview: script_preview(script),
+        });
+
+        let frame = encode_frame(IPC_EXECUTE, script.as_bytes());
+        let mut writer = self.writer.lock().await;
+        let result = async {
+            writer.write_all(&frame).await?;
+            writer.flush().await
+        }
+        .await;
+
+        result.map(|_| id).map_err(|e| {
+            self.alive.store(false, Ordering::SeqCst);
+            e.to_string()
+        })
+    }
+
+    async fn read_frames(
+        self: Arc<Self>,
+        port: u16,
+        mut reader: OwnedReadHalf,
+        output: broadcast::Sender<ExecutorOutput>,
+    ) {
+        let mut header = [0u8; HEADER_LEN];
+        loop {
+            if reader.read_exact(&mut header).await.is_err() {
+                break;
+            }
+
+            let (kind, length) = decode_header(&header);
+            if length > MAX_MESSAGE_LEN {
+                break;
+            }
+
+            let mut payload = vec![0u8; length as usize];
+            if reader.read_exact(&mut payload).await.is_err() {
+                break;
+            }
+
+            let kind = match kind {
+                MESSAGE_PRINT => OutputKind::Print,
+                MESSAGE_ERROR => OutputKind::Error,
+                _ => OutputKind::Unknown,
+            };
+            let execution = self.current.lock().unwrap().frame();
+
+            let _ = output.send(ExecutorOutput {
+                port,
+                kind,
+                message: String::from_utf8_lossy(&payload).into_owned(),
+                execution_id: execution.as_ref().map(|e| e.id.clone()),
+                script_preview: execution.map(|e| e.preview),
+                timestamp: chrono::Utc::now().timestamp_millis(),
+            });
+        }
+
+        self.alive.store(false, Ordering::SeqCst);
+    }
+}
+
+impl SessionPool {
+    pub fn new(output: broadcast::Sender<ExecutorOutput>) -> Self {
+        Self {
+            sessions: AsyncMutex::new(HashMap::new()),
+            output,
+        }
+    }
+
+    pub async fn clear(&self) {
+        for session in self
+            .sessions
+            .lock()
+            .await
+            .drain()
+            .map(|(_, session)| session)
+        {
+            session.alive.store(false, Ordering::SeqCst);
+        }
+    }
+
+    async fn session(&self, host: &str, port: u16) -> Result<Arc<Session>, String> {
+        if let Some(session) = self.sessions.lock().await.get(&port) {
+            if session.is_alive() {
+                return Ok(session.clone());
+            }
+        }
+
+        // Connect without holding the pool lock so sessions on other ports can open in parallel.
+        let (reader, writer) = connect(host, port).await?.into_split();
+
+        let mut sessions = self.sessions.lock().await;
+        if let Some(session) = sessions.get(&port) {
+            if session.is_alive() {
+                return Ok(session.clone());
+            }
+        }
+
+        let session = Arc::new(Session {
+            writer: AsyncMutex::new(writer),
+            alive: AtomicBool::new(true),
+            current: Mutex::new(Attribution::default()),
+        });
+
+        tokio::spawn(
+            session
+                .clone()
+                .read_frames(port, reader, self.output.clone()),
+        );
+        sessions.insert(port, session.clone());
+
+        Ok(session)
+    }
+
+    pub async fn execute(&self, host: &str, port: u16, script: &str) -> Result<String, String> {
+        let session = self.session(host, port).await?;
+        match session.execute(script).await {
+            Ok(execution_id) => Ok(execution_id),
+            Err(e) => {
+                self.sessions.lock().await.remove(&port);
+                Err(e)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ExecutorBackend for MacSploit {
+    fn name(&self) -> &'static str {
+        "MacSploit"
+    }
+
+    fn api_type(&self) -> ApiType {
+        ApiType::MacSploit
+    }
+
+    fn default_endpoint(&self) -> EndpointConfig {
+        EndpointConfig {
+            host: DEFAULT_HOST.to_string(),
+            min_port: 5553,
+            max_port: 5562,
+            secret_path: None,
+            secret_token: None,
+        }
+    }
+
+    async fn handshake(&self, transport: &Transport, port: u16) -> bool {
+        let host = transport.endpoint(ApiType::MacSploit).host;
+        transport.sessions.session(&host, port).await.is_ok()
+    }
+
+    async fn send(
+        &self,
+        transport: &Transport,
+        port: u16,
+        script: &str,
+    ) -> Result<ExecutorResponse, ExecuteError> {
+        let host = transport.endpoint(ApiType::MacSploit).host;
+        let execution_id = transport
+            .sessions
+            .execute(&host, port, script)
+            .await
+            .map_err(ExecuteError::ConnectionError)?;
+
+        Ok(ExecutorResponse {
+            success: true,
+            execution_id: Some(execution_id),
+            ..Default::default()
+        })
+    }
+
+    fn auto_execute_dir(&self, home: &Path) -> PathBuf {
+        home.join("Documents/Macsploit Automatic Execution")
+    }
+
+    fn roblox_files(&self) -> &'static [&'static str] {
+        &["macsploit.dylib"]
+    }
+
+    fn app_bundle(&self) -> Option<&'static str> {
+        Some("MacSploit.app")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_round_trip_through_their_header() {
+        let frame = encode_frame(MESSAGE_ERROR, b"boom");
+        assert_eq!(frame.len(), HEADER_LEN + 4);
+        assert!(frame[1..8].iter().all(|byte| *byte == 0));
+
+        let header: [u8; HEADER_LEN] = frame[..HEADER_LEN].try_into().unwrap();
+        assert_eq!(decode_header(&header), (MESSAGE_ERROR, 4));
+        assert_eq!(&frame[HEADER_LEN..], b"boom");
+    }
+
+    #[test]
+    fn header_length_is_little_endian() {
+        let mut header = [0u8; HEADER_LEN];
+        header[0] = MESSAGE_PRINT;
+        header[8] = 0x01;
+        header[9] = 0x02;
+        assert_eq!(decode_header(&header), (MESSAGE_PRINT, 0x0201));
+
+        let empty = encode_frame(IPC_EXECUTE, b"");
+        assert_eq!(empty, vec![0u8; HEADER_LEN]);
+    }
+
+    #[test]
+    fn script_preview_is_the_first_non_blank_line() {
+        assert_eq!(
+            script_preview("\n   \n  print('hi')  \nwarn()"),
+            "print('hi')"
+        );
+        assert_eq!(script_preview(""), "");
+        assert_eq!(script_preview(&"x".repeat(200)).len(), PREVIEW_LEN);
+    }
+}
@@ -0,0 +1,108 @@
+// Runs Comet headless, or controls a running instance over its local socket.
+//
+//     cargo run -p comet-core --bin comet-daemon
+//     cargo run -p comet-core --bin comet-daemon -- status
+
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+use comet_core::ipc::{self, Request};
+use comet_core::{daemon, ApiType, AppState};
+
+const USAGE: &str = "Usage: comet-daemon [command]
+
+Without a command, runs Comet in the background with no window.
+
+Commands:
+  status                 Show the executor connection
+  reconnect              Retry the connection on the selected port
+  use <executor>         Switch to hydrogen or macsploit
+  execute <file>         Run a script file, or - for stdin
+  workspaces             List workspaces
+  tabs [workspace]       List the tabs of a workspace (default: the active one)
+  history                Show the execution history
+  sync                   Copy auto-execute scripts into the executor's folder
+  stop                   Stop the running daemon";
+
+fn parse_request(args: &[String]) -> Result<Request, String> {
+    match args {
+        [command] if command == "status" => Ok(Request::Status),
+        [command] if command == "reconnect" => Ok(Request::Reconnect),
+        [command] if command == "sync" => Ok(Request::SyncAutoExecute),
+        [command] if command == "stop" => Ok(Request::Shutdown),
+        [command] if command == "workspaces" => Ok(Request::ListWorkspaces),
+        [command] if command == "history" => Ok(Request::History),
+        [command] if command == "tabs" => Ok(Request::ListTabs { workspace_id: None }),
+        [command, workspace_id] if command == "tabs" => Ok(Request::ListTabs {
+            workspace_id: Some(workspace_id.clone()),
+        }),
+        [command, file] if command == "execute" => {
+            let script = if file == "-" {
+                std::io::read_to_string(std::io::stdin())
+            } else {
+                std::fs::read_to_string(file)
+            }
+            .map_err(|e| format!("Failed to read {}: {}", file, e))?;
+            Ok(Request::Execute { script })
+        }
+        [command, executor] if command == "use" => {
+            let api_type = match executor.to_lowercase().as_str() {
+                "hydrogen" => ApiType::Hydrogen,
+                "macsploit" => ApiType::MacSploit,
+                _ => return Err(format!("Unknown executor '{}'", executor)),
+            };
+            Ok(Request::SetApiType { api_type })
+        }
+        _ => Err(format!("Unknown command '{}'", args.join(" "))),
+    }
+}
+
+async fn run_daemon() -> Result<(), String> {
+    let state = AppState::new();
+    let shutdown = Arc::new(Notify::new());
+
+    let ctrl_c = shutdown.clone();
+    tokio::spawn(async move {
+        let _ = tokio::signal::ctrl_c().await;
+        ctrl_c.notify_one();
+    });
+
+    println!(
+        "Comet is running headless. Control socket: {}",
+        ipc::socket_path().display()
+    );
+    daemon::run(state, shutdown).await?;
+    println!("Comet stopped");
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    let result = if args.is_empty() {
+        run_daemon().await
+    } else {
+        match parse_request(&args) {
+            Ok(request) => ipc::send(&request).await.map(|result| {
+                if !result.is_null() {
+                    println!("{:#}", result);
+                }
+            }),
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, USAGE);
+                std::process::exit(2);
+            }
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,119 @@
+// Fake Hydrogen/MacSploit executor for developing Comet without Roblox.
+//
+//     cargo run -p comet-core --features test-support --bin comet-mock-executor -- --hydrogen 6969-6971 --fault execute:500@2
+
+use std::time::Duration;
+
+use comet_core::mock_executor::{MockConfig, MockExecutor};
+
+const USAGE: &str = "Usage: comet-mock-executor [options]
+
+Options:
+  --host <host>          Address to bind (default 127.0.0.1)
+  --hydrogen <ports>     Hydrogen HTTP ports, e.g. 6969, 6969-6971, 6969,7000 or none
+  --macsploit <ports>    MacSploit TCP ports (default 5553)
+  --secret-path <path>   Hydrogen handshake path (default /secret)
+  --token <token>        Hydrogen handshake token (default 0xdeadbeef)
+  --fault <spec>         Inject a failure, may be repeated:
+                           <stage>:<fault>[@<n>|@<n>+|@%<n>]
+                           stage: secret, execute, macsploit
+                           fault: timeout, drop or an HTTP status code
+  --stall-ms <ms>        How long a timeout fault stalls (default 30000)
+  --quiet                Do not log requests";
+
+fn parse_ports(value: &str) -> Result<Vec<u16>, String> {
+    if value == "none" {
+        return Ok(Vec::new());
+    }
+
+    let mut ports = Vec::new();
+    for part in value.split(',') {
+        let parse = |port: &str| {
+            port.trim()
+                .parse::<u16>()
+                .map_err(|_| format!("Invalid port '{}'", port))
+        };
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if start > end {
+                    return Err(format!("Invalid port range '{}'", part));
+                }
+                ports.extend(start..=end);
+            }
+            None => ports.push(parse(part)?),
+        }
+    }
+    Ok(ports)
+}
+
+fn parse_args() -> Result<MockConfig, String> {
+    let mut config = MockConfig {
+        verbose: true,
+        ..Default::default()
+    };
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--host" => config.host = value()?,
+            "--hydrogen" => config.hydrogen_ports = parse_ports(&value()?)?,
+            "--macsploit" => config.macsploit_ports = parse_ports(&value()?)?,
+            "--secret-path" => config.secret_path = value()?,
+            "--token" => config.secret_token = value()?,
+            "--fault" => config.faults.push(value()?.parse()?),
+            "--stall-ms" => {
+                let value = value()?;
+                let millis = value
+                    .parse()
+                    .map_err(|_| format!("Invalid duration '{}'", value))?;
+                config.stall = Duration::from_millis(millis);
+            }
+            "--quiet" => config.verbose = false,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    Ok(config)
+}
+
+#[tokio::main]
+async fn main() {
+    let config = match parse_args() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let executor = match MockExecutor::start(config).await {
+        Ok(executor) => executor,
+        Err(e) => {
+            eprintln!("Failed to start mock executor: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!(
+        "Mock executor running (Hydrogen: {:?}, MacSploit: {:?}). Press Ctrl+C to stop.",
+        executor.hydrogen_ports(),
+        executor.macsploit_ports()
+    );
+    let _ = tokio::signal::ctrl_c().await;
+    executor.shutdown();
+
+    println!(
+        "\nServed {} connections, executed {} scripts.",
+        executor.connections(),
+        executor.received().len()
+    );
+}
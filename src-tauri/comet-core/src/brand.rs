@@ -0,0 +1,117 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::config::{BrandConfig, CometConfig, NotificationConfig};
+use crate::paths;
+
+const DEFAULT_BRAND: &str = "default";
+
+/// The branding of the running build: what the app is called and which executor it drives.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Brand {
+    pub id: String,
+    #[serde(flatten)]
+    pub config: BrandConfig,
+}
+
+impl Brand {
+    pub fn product_name(&self) -> &str {
+        &self.config.product_name
+    }
+
+    /// The executor's name, e.g. `Hydrogen`, as used by its installer and app bundle.
+    pub fn executor_name(&self) -> &str {
+        &self.config.executor
+    }
+
+    pub fn install_url(&self) -> &str {
+        &self.config.install_url
+    }
+
+    pub fn notifications(&self) -> &NotificationConfig {
+        &self.config.notifications
+    }
+
+    /// The executor's app in `/Applications`.
+    pub fn app_path(&self) -> PathBuf {
+        paths::applications_dir().join(format!("{}.app", self.config.executor))
+    }
+
+    /// The dylib the executor injects, inside its app.
+    pub fn dylib_path(&self) -> PathBuf {
+        paths::applications_dir().join(&self.config.dylib_path)
+    }
+
+    /// The executor's own folder in the home folder, e.g. `~/Hydrogen`.
+    pub fn data_dir(&self) -> Result<PathBuf, String> {
+        Ok(paths::home_dir()?.join(&self.config.data_folder))
+    }
+
+    /// The executor's hidden settings folder, e.g. `~/.hydrogen`.
+    pub fn settings_dir(&self) -> Result<PathBuf, String> {
+        Ok(paths::home_dir()?.join(format!(".{}", self.config.data_folder.to_lowercase())))
+    }
+
+    /// The endpoint that checks the user's license key, if the executor has one.
+    pub fn key_validation_url(&self) -> Option<&str> {
+        self.config.key_validation_url.as_deref()
+    }
+
+    /// Where this brand's release manifest is published, if updates are published at all.
+    pub fn update_manifest(&self) -> Option<&str> {
+        self.config.update_manifest.as_deref()
+    }
+
+    /// Where the executor stores the user's license key.
+    pub fn key_file(&self) -> Result<PathBuf, String> {
+        Ok(paths::home_dir()?.join(&self.config.key_file))
+    }
+}
+
+/// Every brand in a [`CometConfig`], by id.
+#[derive(Debug, Clone)]
+pub struct BrandRegistry {
+    brands: HashMap<String, BrandConfig>,
+}
+
+impl BrandRegistry {
+    pub fn from_config(config: CometConfig) -> Self {
+        Self {
+            brands: config.brands,
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<Brand> {
+        self.brands.get(id).map(|config| Brand {
+            id: id.to_string(),
+            config: config.clone(),
+        })
+    }
+
+    // A config without a default brand falls back to the compiled-in one, which always has it.
+    fn get_or_default(&self, id: &str) -> Brand {
+        self.get(id)
+            .or_else(|| self.get(DEFAULT_BRAND))
+            .or_else(|| Self::from_config(CometConfig::defaults()).get(DEFAULT_BRAND))
+            .expect("The default config has no default brand")
+    }
+
+    /// The brand whose product name matches the app's, e.g. `package_info().name`. Unknown
+    /// names get the default brand.
+    pub fn for_product(&self, product_name: &str) -> Brand {
+        let id = self
+            .brands
+            .iter()
+            .find(|(_, config)| config.product_name.eq_ignore_ascii_case(product_name))
+            .map(|(id, _)| id.as_str())
+            .unwrap_or(DEFAULT_BRAND);
+        self.get_or_default(id)
+    }
+
+    /// The brand used when there is no app to ask, as in the daemon.
+    pub fn default_brand(&self) -> Brand {
+        self.get_or_default(DEFAULT_BRAND)
+    }
+}
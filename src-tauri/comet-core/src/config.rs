@@ -0,0 +1,285 @@
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{mpsc, Arc, RwLock};
+use std::thread;
+
+use crate::brand::{Brand, BrandRegistry};
+use crate::paths;
+
+const CONFIG_FILE: &str = "config.json";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationConfig {
+    pub title: String,
+    pub disconnect: DisconnectConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DisconnectConfig {
+    pub body: String,
+}
+
+/// One white-label build of Comet. Paths are relative: `dylib_path` to `/Applications`,
+/// `data_folder` and `key_file` to the home folder.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BrandConfig {
+    pub product_name: String,
+    pub icon_path: String,
+    /// The executor this build ships with, as named by its installer and app bundle.
+    pub executor: String,
+    pub install_url: String,
+    pub dylib_path: String,
+    pub data_folder: String,
+    pub key_file: String,
+    /// Where the executor checks license keys. Without one, keys aren't validated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_validation_url: Option<String>,
+    /// Where the release manifest for this brand's Comet builds is published. Without one,
+    /// Comet doesn't check for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_manifest: Option<String>,
+    pub notifications: NotificationConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct CometConfig {
+    pub brands: HashMap<String, BrandConfig>,
+}
+
+// Shared with the build tools in `.comet/build`, which pick the brand to build from it.
+const DEFAULT_CONFIG: &str = include_str!("../../../.comet/build/config.json");
+
+fn is_relative_inside(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+impl CometConfig {
+    /// The config compiled into this build, the bottom layer of every load.
+    pub fn defaults() -> Self {
+        serde_json::from_str(DEFAULT_CONFIG).expect("Invalid default config")
+    }
+
+    /// Checks what the types can't: a `default` brand exists, names are set, URLs are URLs and
+    /// paths stay inside the folder they are relative to.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        if !self.brands.contains_key("default") {
+            errors.push("brands: a \"default\" brand is required".to_string());
+        }
+
+        for (id, brand) in &self.brands {
+            for (field, value) in [
+                ("productName", &brand.product_name),
+                ("executor", &brand.executor),
+                ("notifications.title", &brand.notifications.title),
+            ] {
+                if value.trim().is_empty() {
+                    errors.push(format!("brands.{}.{}: must not be empty", id, field));
+                }
+            }
+            if !brand.install_url.starts_with("https://") {
+                errors.push(format!("brands.{}.installUrl: must be an https URL", id));
+            }
+            for (field, url) in [
+                ("keyValidationUrl", &brand.key_validation_url),
+                ("updateManifest", &brand.update_manifest),
+            ] {
+                if url.as_ref().is_some_and(|url| !url.starts_with("https://")) {
+                    errors.push(format!("brands.{}.{}: must be an https URL", id, field));
+                }
+            }
+            for (field, value) in [
+                ("dylibPath", &brand.dylib_path),
+                ("dataFolder", &brand.data_folder),
+                ("keyFile", &brand.key_file),
+            ] {
+                if !is_relative_inside(value) {
+                    errors.push(format!(
+                        "brands.{}.{}: must be a relative path without `..`",
+                        id, field
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            errors.sort();
+            Err(errors.join("; "))
+        }
+    }
+}
+
+/// `.comet/config/config.json` next to the executable, shipped with the build.
+pub fn bundled_path() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    Some(
+        exe.parent()?
+            .join(".comet")
+            .join("config")
+            .join(CONFIG_FILE),
+    )
+}
+
+/// `config.json` in the app data dir, where users override single values.
+pub fn override_path() -> PathBuf {
+    paths::app_data_dir().join(CONFIG_FILE)
+}
+
+/// The merged config and the layers that were skipped because they didn't parse or validate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoadedConfig {
+    pub config: CometConfig,
+    pub errors: Vec<String>,
+}
+
+// Objects are merged key by key, anything else in `layer` replaces what's in `base`.
+pub(crate) fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Object(base), Value::Object(layer)) => {
+            for (key, value) in layer {
+                merge(base.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+fn parse(value: Value) -> Result<CometConfig, String> {
+    let config: CometConfig = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Loads the defaults, then the bundled file, then the user override. A layer that is
+/// unreadable or leaves the config invalid is skipped and reported in `errors`.
+pub fn load_layers() -> LoadedConfig {
+    let mut merged = serde_json::from_str::<Value>(DEFAULT_CONFIG).expect("Invalid default config");
+    let mut errors = Vec::new();
+
+    for path in bundled_path().into_iter().chain([override_path()]) {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => {
+                errors.push(format!("{}: {}", path.display(), e));
+                continue;
+            }
+        };
+
+        let mut candidate = merged.clone();
+        let layered = serde_json::from_str(&content)
+            .map_err(|e| e.to_string())
+            .and_then(|layer| {
+                merge(&mut candidate, layer);
+                parse(candidate.clone())
+            });
+        match layered {
+            Ok(_) => merged = candidate,
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    LoadedConfig {
+        config: parse(merged).unwrap_or_else(|_| CometConfig::defaults()),
+        errors,
+    }
+}
+
+/// The current config, shared by everything that needs branding. Cheap to clone.
+#[derive(Debug, Clone)]
+pub struct ConfigStore {
+    loaded: Arc<RwLock<LoadedConfig>>,
+    product_name: Option<String>,
+}
+
+impl ConfigStore {
+    /// Loads every layer. `product_name` picks the brand, e.g. `package_info().name`; without
+    /// one the default brand is used.
+    pub fn load(product_name: Option<String>) -> Self {
+        Self {
+            loaded: Arc::new(RwLock::new(load_layers())),
+            product_name,
+        }
+    }
+
+    pub fn current(&self) -> LoadedConfig {
+        self.loaded.read().unwrap().clone()
+    }
+
+    pub fn brand(&self) -> Brand {
+        let registry = BrandRegistry::from_config(self.current().config);
+        match &self.product_name {
+            Some(product_name) => registry.for_product(product_name),
+            None => registry.default_brand(),
+        }
+    }
+
+    /// Reads the layers again and returns the new config if anything changed.
+    pub fn reload(&self) -> Option<LoadedConfig> {
+        let loaded = load_layers();
+        let mut current = self.loaded.write().unwrap();
+        if *current == loaded {
+            return None;
+        }
+        *current = loaded.clone();
+        Some(loaded)
+    }
+}
+
+/// Keeps the config watcher alive; dropping it stops watching.
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+/// Reloads `store` whenever the bundled or override config file changes and calls `on_change`
+/// with the new config.
+pub fn watch(
+    store: ConfigStore,
+    on_change: impl Fn(LoadedConfig) + Send + 'static,
+) -> Result<ConfigWatcher, String> {
+    let (events, changes) = mpsc::channel::<notify::Result<notify::Event>>();
+    let mut watcher = notify::recommended_watcher(events).map_err(|e| e.to_string())?;
+
+    let files = bundled_path()
+        .into_iter()
+        .chain([override_path()])
+        .collect::<Vec<_>>();
+    for dir in files.iter().filter_map(|file| file.parent()) {
+        if dir.exists() {
+            watcher
+                .watch(dir, RecursiveMode::NonRecursive)
+                .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e))?;
+        }
+    }
+
+    thread::spawn(move || {
+        while let Ok(event) = changes.recv() {
+            let Ok(event) = event else {
+                continue;
+            };
+            let is_config =
+                |path: &PathBuf| path.file_name().is_some_and(|name| name == CONFIG_FILE);
+            if !event.paths.iter().any(is_config) {
+                continue;
+            }
+            if let Some(loaded) = store.reload() {
+                on_change(loaded);
+            }
+        }
+    });
+
+    Ok(ConfigWatcher { _watcher: watcher })
+}
@@ -0,0 +1,330 @@
+use futures_util::future::join_all;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, RwLock};
+
+use crate::backend::{
+    EndpointConfig, ExecuteError, ExecutorBackend, ExecutorOutput, ExecutorResponse, Transport,
+};
+use crate::discovery::{self, DiscoveryOptions};
+use crate::ApiType;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExecutorInstance {
+    pub port: u16,
+    pub api_type: ApiType,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExecutionTarget {
+    All,
+    Port { port: u16 },
+    Ports { ports: Vec<u16> },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExecutionResult {
+    pub success: bool,
+    pub status: Option<u16>,
+    pub response: Option<String>,
+    pub port: u16,
+    pub api_type: ApiType,
+    pub elapsed_ms: u64,
+    pub execution_id: Option<String>,
+}
+
+impl ExecutionResult {
+    fn new(response: ExecutorResponse, port: u16, api_type: ApiType, started: Instant) -> Self {
+        Self {
+            success: response.success,
+            status: response.status,
+            response: response.body,
+            port,
+            api_type,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            execution_id: response.execution_id,
+        }
+    }
+
+    pub fn error_message(&self) -> Option<String> {
+        if self.success {
+            return None;
+        }
+        let status = self
+            .status
+            .map(|status| format!("HTTP {}", status))
+            .unwrap_or_else(|| "Execution failed".to_string());
+        match self.response.as_deref() {
+            Some(body) if !body.is_empty() => Some(format!("{}: {}", status, body)),
+            _ => Some(status),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceResult {
+    pub port: u16,
+    pub success: bool,
+    pub error: Option<String>,
+    pub result: Option<ExecutionResult>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Endpoint {
+    api_type: ApiType,
+    port: Option<u16>,
+}
+
+#[derive(Debug)]
+pub struct ConnectionManager {
+    transport: Transport,
+    endpoint: RwLock<Endpoint>,
+    discovery: RwLock<DiscoveryOptions>,
+}
+
+impl ConnectionManager {
+    pub fn new(api_type: ApiType, endpoints: HashMap<ApiType, EndpointConfig>) -> Self {
+        let client = Client::builder().timeout(REQUEST_TIMEOUT).build().unwrap();
+        Self {
+            transport: Transport::new(client, endpoints),
+            endpoint: RwLock::new(Endpoint {
+                api_type,
+                port: None,
+            }),
+            discovery: RwLock::new(DiscoveryOptions::default()),
+        }
+    }
+
+    /// Uses `options` for discovery instead of the defaults.
+    pub fn with_discovery_options(mut self, options: DiscoveryOptions) -> Self {
+        self.discovery = RwLock::new(options);
+        self
+    }
+
+    pub async fn discovery_options(&self) -> DiscoveryOptions {
+        *self.discovery.read().await
+    }
+
+    pub async fn set_discovery_options(&self, options: DiscoveryOptions) {
+        *self.discovery.write().await = options;
+    }
+
+    pub fn endpoint(&self, api_type: ApiType) -> EndpointConfig {
+        self.transport.endpoint(api_type)
+    }
+
+    pub async fn set_endpoints(&self, endpoints: HashMap<ApiType, EndpointConfig>) {
+        self.transport.set_endpoints(endpoints).await;
+        self.disconnect().await;
+    }
+
+    pub async fn discover(&self) -> Vec<u16> {
+        let (backend, _) = self.snapshot().await;
+        discovery::discover(backend, &self.transport, self.discovery_options().await).await
+    }
+
+    pub fn subscribe_output(&self) -> broadcast::Receiver<ExecutorOutput> {
+        self.transport.subscribe()
+    }
+
+    pub async fn api_type(&self) -> ApiType {
+        self.endpoint.read().await.api_type
+    }
+
+    pub async fn port(&self) -> Option<u16> {
+        self.endpoint.read().await.port
+    }
+
+    pub async fn set_api_type(&self, api_type: ApiType) {
+        *self.endpoint.write().await = Endpoint {
+            api_type,
+            port: None,
+        };
+    }
+
+    pub async fn disconnect(&self) {
+        self.endpoint.write().await.port = None;
+    }
+
+    // Only record the outcome if nobody switched executor while we were talking to the old one.
+    async fn set_port(&self, api_type: ApiType, port: Option<u16>) {
+        let mut endpoint = self.endpoint.write().await;
+        if endpoint.api_type == api_type {
+            endpoint.port = port;
+        }
+    }
+
+    async fn snapshot(&self) -> (&'static dyn ExecutorBackend, Endpoint) {
+        let endpoint = *self.endpoint.read().await;
+        (endpoint.api_type.backend(), endpoint)
+    }
+
+    pub async fn is_connected(&self) -> bool {
+        let (backend, endpoint) = self.snapshot().await;
+        if let Some(port) = endpoint.port {
+            if backend.handshake(&self.transport, port).await {
+                return true;
+            }
+            self.set_port(endpoint.api_type, None).await;
+        }
+        false
+    }
+
+    pub async fn connect(&self, port: u16) -> bool {
+        let (backend, endpoint) = self.snapshot().await;
+        if backend.handshake(&self.transport, port).await {
+            self.set_port(endpoint.api_type, Some(port)).await;
+            return true;
+        }
+        false
+    }
+
+    pub async fn send(&self, script: &str) -> Result<ExecutionResult, ExecuteError> {
+        let started = Instant::now();
+        let (backend, endpoint) = self.snapshot().await;
+        let port = endpoint
+            .port
+            .ok_or_else(|| ExecuteError::ConnectionError("Not connected".to_string()))?;
+
+        match backend.send(&self.transport, port, script).await {
+            Ok(response) => Ok(ExecutionResult::new(
+                response,
+                port,
+                endpoint.api_type,
+                started,
+            )),
+            Err(e) => {
+                self.set_port(endpoint.api_type, None).await;
+                Err(e)
+            }
+        }
+    }
+
+    pub async fn execute(&self, script: &str) -> Result<ExecutionResult, ExecuteError> {
+        let started = Instant::now();
+        let (backend, endpoint) = self.snapshot().await;
+        let mut last_error = None;
+
+        if let Some(port) = endpoint.port {
+            match backend.send(&self.transport, port, script).await {
+                Ok(response) => {
+                    return Ok(ExecutionResult::new(
+                        response,
+                        port,
+                        endpoint.api_type,
+                        started,
+                    ))
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        let ports = discovery::discover(backend, &self.transport, self.discovery_options().await)
+            .await
+            .into_iter()
+            .filter(|port| Some(*port) != endpoint.port);
+
+        for port in ports {
+            match backend.send(&self.transport, port, script).await {
+                Ok(response) => {
+                    self.set_port(endpoint.api_type, Some(port)).await;
+                    return Ok(ExecutionResult::new(
+                        response,
+                        port,
+                        endpoint.api_type,
+                        started,
+                    ));
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        self.set_port(endpoint.api_type, None).await;
+        let (min_port, max_port) = self.endpoint(endpoint.api_type).port_range();
+        Err(ExecuteError::ConnectionError(match last_error {
+            Some(e) => format!(
+                "Could not reach {} on ports {}-{}. Last error: {}",
+                backend.name(),
+                min_port,
+                max_port,
+                e
+            ),
+            None => format!(
+                "No {} instance found on ports {}-{}",
+                backend.name(),
+                min_port,
+                max_port
+            ),
+        }))
+    }
+
+    pub async fn list_instances(&self) -> Vec<ExecutorInstance> {
+        let api_type = self.api_type().await;
+        self.discover()
+            .await
+            .into_iter()
+            .map(|port| ExecutorInstance { port, api_type })
+            .collect()
+    }
+
+    pub async fn send_to(&self, script: &str, target: ExecutionTarget) -> Vec<InstanceResult> {
+        let mut ports = match target {
+            ExecutionTarget::All => self
+                .list_instances()
+                .await
+                .into_iter()
+                .map(|instance| instance.port)
+                .collect(),
+            ExecutionTarget::Port { port } => vec![port],
+            ExecutionTarget::Ports { ports } => ports,
+        };
+        ports.sort_unstable();
+        ports.dedup();
+
+        let (backend, endpoint) = self.snapshot().await;
+        let (min_port, max_port) = self.endpoint(endpoint.api_type).port_range();
+        let sends = ports.into_iter().map(|port| async move {
+            // Only ports the executor is configured on are contacted, never arbitrary ones.
+            if !(min_port..=max_port).contains(&port) {
+                return InstanceResult {
+                    port,
+                    success: false,
+                    error: Some(format!(
+                        "Port {} is outside the {} range {}-{}",
+                        port,
+                        backend.name(),
+                        min_port,
+                        max_port
+                    )),
+                    result: None,
+                };
+            }
+
+            let started = Instant::now();
+            match backend.send(&self.transport, port, script).await {
+                Ok(response) => {
+                    let result = ExecutionResult::new(response, port, endpoint.api_type, started);
+                    InstanceResult {
+                        port,
+                        success: result.success,
+                        error: result.error_message(),
+                        result: Some(result),
+                    }
+                }
+                Err(e) => InstanceResult {
+                    port,
+                    success: false,
+                    error: Some(e.to_string()),
+                    result: None,
+                },
+            }
+        });
+
+        join_all(sends).await
+    }
+}
@@ -0,0 +1,134 @@
+use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use crate::config::{self, ConfigStore, LoadedConfig};
+use crate::detector::{self, DetectionReport};
+use crate::logs::{self, LogEvent};
+use crate::{auto_execute, ipc, process, supervisor, AppState};
+
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(5);
+
+// The text is passed as arguments rather than spliced into the script, so brand strings need
+// no AppleScript escaping.
+fn notification_args(title: &str, body: &str) -> Vec<String> {
+    [
+        "-e",
+        "on run argv",
+        "-e",
+        "display notification (item 1 of argv) with title (item 2 of argv)",
+        "-e",
+        "end run",
+        body,
+        title,
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+fn notify_disconnect(config: &ConfigStore) {
+    let brand = config.brand();
+    let notifications = brand.notifications();
+    let _ = Command::new("osascript")
+        .args(notification_args(
+            &notifications.title,
+            &notifications.disconnect.body,
+        ))
+        .spawn();
+}
+
+fn on_log_event(config: &ConfigStore, event: LogEvent) {
+    if event == LogEvent::Disconnected {
+        println!("Roblox lost its connection to the game server");
+        notify_disconnect(config);
+    }
+}
+
+fn on_config_changed(loaded: LoadedConfig) {
+    println!("Reloaded the config");
+    for error in loaded.errors {
+        eprintln!("Ignored config layer: {}", error);
+    }
+}
+
+fn on_detection_changed(report: DetectionReport) {
+    let found = report
+        .executors
+        .iter()
+        .filter(|executor| executor.injected)
+        .map(|executor| executor.name.as_str())
+        .collect::<Vec<_>>();
+    if found.is_empty() {
+        println!("No executor is installed in Roblox");
+    } else {
+        println!("Installed executors: {}", found.join(", "));
+    }
+}
+
+// Keeps the log watcher attached once Roblox writes a log and mirrors auto-execute scripts
+// into the selected executor's folder.
+async fn maintain(state: AppState, config: ConfigStore) {
+    loop {
+        let log_config = config.clone();
+        if !logs::WATCHING.load(std::sync::atomic::Ordering::SeqCst)
+            && logs::start_watching(move |event| on_log_event(&log_config, event)).is_ok()
+        {
+            println!("Watching the Roblox log");
+        }
+
+        let api_type = state.status().await.api_type;
+        match auto_execute::sync_auto_execute(api_type) {
+            Ok(0) => {}
+            Ok(copied) => println!("Synced {} auto-execute scripts", copied),
+            Err(e) => eprintln!("Failed to sync auto-execute scripts: {}", e),
+        }
+
+        tokio::time::sleep(MAINTENANCE_INTERVAL).await;
+    }
+}
+
+/// Runs Comet without a window: the connection supervisor, the Roblox process monitor and log watcher,
+/// auto-execute syncing and the control socket at [`ipc::socket_path`].
+/// Returns once a `shutdown` request arrives or `shutdown` is notified.
+pub async fn run(state: AppState, shutdown: Arc<Notify>) -> Result<(), String> {
+    let socket = ipc::bind().await?;
+
+    let config = ConfigStore::load(None);
+    for error in config.current().errors {
+        eprintln!("Ignored config layer: {}", error);
+    }
+    let config_watcher = config::watch(config.clone(), on_config_changed)
+        .map_err(|e| eprintln!("Not watching the config: {}", e))
+        .ok();
+
+    let detection = detector::watch(on_detection_changed)
+        .map_err(|e| eprintln!("Not watching for executor installs: {}", e))
+        .ok();
+    let supervisor = tokio::spawn(supervisor::run(state.clone()));
+    let processes = tokio::spawn(process::run(state.clone()));
+    let maintenance = tokio::spawn(maintain(state.clone(), config));
+
+    ipc::serve(socket, state, Some(shutdown)).await;
+
+    supervisor.abort();
+    processes.abort();
+    maintenance.abort();
+    logs::stop_watching();
+    drop(detection);
+    drop(config_watcher);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notification_text_is_passed_verbatim() {
+        let args = notification_args("Cömet \"β\"", "Roblox's server\nis gone \\o/");
+        assert_eq!(args[6], "Roblox's server\nis gone \\o/");
+        assert_eq!(args[7], "Cömet \"β\"");
+        assert!(args[..6].iter().all(|arg| !arg.contains("Roblox")));
+    }
+}
@@ -0,0 +1,190 @@
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::SystemTime;
+
+use crate::{paths, ApiType};
+
+const ROBLOX_APP: &str = "Roblox.app";
+
+/// An executor with at least one of its files on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DetectedExecutor {
+    pub api_type: ApiType,
+    pub name: String,
+    /// Every file or app bundle of the executor that exists.
+    pub install_paths: Vec<String>,
+    /// Read from the executor app's `Info.plist`, when it has a readable one.
+    pub version: Option<String>,
+    /// Whether the executor's files are inside Roblox, i.e. it can be connected to.
+    pub injected: bool,
+}
+
+/// What is installed, and which executor Comet should talk to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DetectionReport {
+    pub roblox_installed: bool,
+    pub roblox_version: Option<String>,
+    pub executors: Vec<DetectedExecutor>,
+    pub recommended: ApiType,
+    /// More than one executor is injected into Roblox.
+    pub conflict: bool,
+}
+
+/// `Roblox.app/Contents/MacOS`, where executors place their files.
+pub fn roblox_macos_dir() -> PathBuf {
+    paths::applications_dir()
+        .join(ROBLOX_APP)
+        .join("Contents")
+        .join("MacOS")
+}
+
+// Plists written by Xcode are XML; binary ones are skipped.
+fn read_bundle_version(bundle: &Path) -> Option<String> {
+    let plist = fs::read_to_string(bundle.join("Contents").join("Info.plist")).ok()?;
+    let key = plist.find("<key>CFBundleShortVersionString</key>")?;
+    let rest = &plist[key..];
+    let start = rest.find("<string>")? + "<string>".len();
+    let end = rest[start..].find("</string>")?;
+    Some(rest[start..start + end].trim().to_string())
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn detect_one(api_type: ApiType, macos_dir: &Path) -> Option<(DetectedExecutor, SystemTime)> {
+    let backend = api_type.backend();
+    let mut install_paths = Vec::new();
+    let mut installed_at = SystemTime::UNIX_EPOCH;
+
+    for file in backend.roblox_files() {
+        let path = macos_dir.join(file);
+        if path.exists() {
+            installed_at = installed_at.max(modified(&path).unwrap_or(SystemTime::UNIX_EPOCH));
+            install_paths.push(path);
+        }
+    }
+    let injected = !install_paths.is_empty();
+
+    let mut version = None;
+    if let Some(bundle) = backend.app_bundle() {
+        let path = paths::applications_dir().join(bundle);
+        if path.exists() {
+            version = read_bundle_version(&path);
+            install_paths.push(path);
+        }
+    }
+
+    if install_paths.is_empty() {
+        return None;
+    }
+
+    let executor = DetectedExecutor {
+        api_type,
+        name: backend.name().to_string(),
+        install_paths: install_paths
+            .iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect(),
+        version,
+        injected,
+    };
+    Some((executor, installed_at))
+}
+
+/// Looks for every executor. When several are injected, the most recently installed one is
+/// recommended.
+pub fn detect() -> DetectionReport {
+    let macos_dir = roblox_macos_dir();
+    let found = ApiType::ALL
+        .into_iter()
+        .filter_map(|api_type| detect_one(api_type, &macos_dir))
+        .collect::<Vec<_>>();
+
+    let injected = found
+        .iter()
+        .filter(|(executor, _)| executor.injected)
+        .collect::<Vec<_>>();
+    let recommended = injected
+        .iter()
+        .max_by_key(|(_, installed_at)| *installed_at)
+        .map(|(executor, _)| executor.api_type)
+        .unwrap_or_default();
+
+    DetectionReport {
+        roblox_installed: macos_dir.exists(),
+        roblox_version: read_bundle_version(&paths::applications_dir().join(ROBLOX_APP)),
+        conflict: injected.len() > 1,
+        recommended,
+        executors: found.into_iter().map(|(executor, _)| executor).collect(),
+    }
+}
+
+pub fn detect_executor() -> ApiType {
+    detect().recommended
+}
+
+/// Keeps the detection watcher alive; dropping it stops watching.
+pub struct DetectionWatcher {
+    _watcher: Arc<Mutex<RecommendedWatcher>>,
+}
+
+// Roblox being reinstalled replaces its folder, so the watch on it follows the folder's existence.
+fn follow_roblox_dir(watcher: &Mutex<RecommendedWatcher>, macos_dir: &Path, watching: &mut bool) {
+    let mut watcher = watcher.lock().unwrap();
+    if macos_dir.exists() {
+        if !*watching {
+            *watching = watcher
+                .watch(macos_dir, RecursiveMode::NonRecursive)
+                .is_ok();
+        }
+    } else if *watching {
+        let _ = watcher.unwatch(macos_dir);
+        *watching = false;
+    }
+}
+
+/// Re-runs detection whenever `/Applications` or `Roblox.app/Contents/MacOS` changes and calls
+/// `on_change` with the new report if it differs from the last one.
+pub fn watch(
+    on_change: impl Fn(DetectionReport) + Send + 'static,
+) -> Result<DetectionWatcher, String> {
+    let applications_dir = paths::applications_dir();
+    let macos_dir = roblox_macos_dir();
+    let mut last = detect();
+
+    let (events, changes) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(events).map_err(|e| e.to_string())?;
+    watcher
+        .watch(&applications_dir, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch {}: {}", applications_dir.display(), e))?;
+
+    let watcher = Arc::new(Mutex::new(watcher));
+    let mut watching_roblox = false;
+    follow_roblox_dir(&watcher, &macos_dir, &mut watching_roblox);
+
+    let weak = Arc::downgrade(&watcher);
+    thread::spawn(move || {
+        while let Ok(event) = changes.recv() {
+            let Some(watcher) = weak.upgrade() else {
+                break;
+            };
+            if event.is_err() {
+                continue;
+            }
+            follow_roblox_dir(&watcher, &macos_dir, &mut watching_roblox);
+
+            let report = detect();
+            if report != last {
+                last = report.clone();
+                on_change(report);
+            }
+        }
+    });
+
+    Ok(DetectionWatcher { _watcher: watcher })
+}
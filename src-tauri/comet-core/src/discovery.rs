@@ -0,0 +1,75 @@
+use futures_util::future::join_all;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::backend::{ExecutorBackend, Transport};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiscoveryOptions {
+    pub connect_timeout_ms: u64,
+    pub handshake_timeout_ms: u64,
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        Self {
+            connect_timeout_ms: 150,
+            handshake_timeout_ms: 500,
+        }
+    }
+}
+
+impl DiscoveryOptions {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.connect_timeout_ms == 0 || self.handshake_timeout_ms == 0 {
+            return Err("Discovery timeouts must be greater than zero".to_string());
+        }
+        Ok(())
+    }
+
+    fn connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.connect_timeout_ms)
+    }
+
+    fn handshake_timeout(&self) -> Duration {
+        Duration::from_millis(self.handshake_timeout_ms)
+    }
+}
+
+async fn is_port_open(host: &str, port: u16, options: DiscoveryOptions) -> bool {
+    matches!(
+        timeout(options.connect_timeout(), TcpStream::connect((host, port))).await,
+        Ok(Ok(_))
+    )
+}
+
+// Every port is probed at once; a cheap TCP connect filters out closed ports before the
+// executor handshake runs, so the whole scan is bounded by the two timeouts.
+pub async fn discover(
+    backend: &dyn ExecutorBackend,
+    transport: &Transport,
+    options: DiscoveryOptions,
+) -> Vec<u16> {
+    let endpoint = transport.endpoint(backend.api_type());
+    let (min_port, max_port) = endpoint.port_range();
+    let host = endpoint.host.as_str();
+
+    let probes = (min_port..=max_port).map(|port| async move {
+        if !is_port_open(host, port, options).await {
+            return None;
+        }
+
+        timeout(
+            options.handshake_timeout(),
+            backend.handshake(transport, port),
+        )
+        .await
+        .unwrap_or(false)
+        .then_some(port)
+    });
+
+    join_all(probes).await.into_iter().flatten().collect()
+}
@@ -0,0 +1,106 @@
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::paths;
+use crate::persist;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    pub id: String,
+    pub timestamp: i64,
+    pub content: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+fn get_execution_history_dir() -> PathBuf {
+    let mut path = paths::app_data_dir();
+    path.push("execution_history");
+    fs::create_dir_all(&path).expect("Failed to create directory");
+    path
+}
+
+fn get_execution_history_file() -> PathBuf {
+    let mut path = get_execution_history_dir();
+    path.push("history.json");
+    path
+}
+
+pub fn load_execution_history() -> Result<Vec<ExecutionRecord>, String> {
+    let history_file = get_execution_history_file();
+    Ok(persist::read_with(&history_file, load_history_file)?.unwrap_or_default())
+}
+
+fn load_history_file(path: &Path) -> Result<Vec<ExecutionRecord>, String> {
+    let metadata = fs::metadata(path).map_err(|e| e.to_string())?;
+    let file_size = metadata.len();
+    
+    if file_size > 5_000_000 {
+        load_history_streaming(path)
+    } else {
+        load_history_standard(path)
+    }
+}
+
+fn load_history_standard(path: &Path) -> Result<Vec<ExecutionRecord>, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let reader = BufReader::new(file);
+    serde_json::from_reader(reader).map_err(|e| {
+        eprintln!("JSON parse error: {}", e);
+        "Failed to parse execution history. File may be corrupted.".to_string()
+    })
+}
+
+fn load_history_streaming(path: &Path) -> Result<Vec<ExecutionRecord>, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let reader = BufReader::with_capacity(8192, file);
+    
+    match serde_json::from_reader::<_, Vec<ExecutionRecord>>(reader) {
+        Ok(mut records) => {
+            if records.len() > 1000 {
+                records.truncate(1000);
+            }
+            Ok(records)
+        }
+        Err(e) => {
+            eprintln!("Streaming JSON parse error: {}", e);
+            Err("Failed to parse large execution history file. Consider clearing history.".to_string())
+        }
+    }
+}
+
+/// Prepends a record and trims the history to `max_items`.
+pub fn save_execution_record(record: ExecutionRecord, max_items: usize) -> Result<(), String> {
+    let history_file = get_execution_history_file();
+    
+    let mut history = if history_file.exists() {
+        load_execution_history().unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    history.insert(0, record);
+
+    if history.len() > max_items {
+        history.truncate(max_items);
+    }
+
+    persist::write_json(&history_file, &history).map_err(|e| {
+        eprintln!("Failed to write execution history: {}", e);
+        e
+    })?;
+
+    Ok(())
+}
+
+pub fn clear_execution_history() -> Result<(), String> {
+    let history_file = get_execution_history_file();
+    
+    if history_file.exists() {
+        persist::write_json(&history_file, &Vec::<ExecutionRecord>::new())?;
+    }
+
+    Ok(())
+} 
\ No newline at end of file
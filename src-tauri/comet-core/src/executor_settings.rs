@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::backend::EndpointConfig;
+use crate::discovery::DiscoveryOptions;
+use crate::settings;
+use crate::ApiType;
+
+// Discovery probes every port in the range at once, so keep ranges to a sane size.
+const MAX_PORT_SPAN: u16 = 1024;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_port: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_port: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_token: Option<String>,
+}
+
+/// Where Comet looks for each executor and how long discovery waits, the `executor` section
+/// of the settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutorSettings {
+    #[serde(default)]
+    pub overrides: HashMap<ApiType, EndpointOverride>,
+    #[serde(default)]
+    pub discovery: DiscoveryOptions,
+}
+
+impl ExecutorSettings {
+    // Unreadable or invalid settings fall back to the built-in endpoints rather than
+    // keeping the app from starting.
+    pub fn load() -> Self {
+        let settings = settings::load().and_then(|settings| {
+            let executor = settings.executor;
+            executor.validate().map(|_| executor)
+        });
+
+        settings.unwrap_or_else(|e| {
+            eprintln!("Ignoring executor settings: {}", e);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        settings::update(|settings| settings.executor = self.clone())
+            .map(|_| ())
+            .map_err(|e| format!("Failed to write executor settings: {}", e))
+    }
+
+    pub fn resolve(&self, api_type: ApiType) -> EndpointConfig {
+        let mut endpoint = api_type.backend().default_endpoint();
+        let Some(overrides) = self.overrides.get(&api_type) else {
+            return endpoint;
+        };
+
+        if let Some(host) = &overrides.host {
+            endpoint.host = host.trim().to_string();
+        }
+        if let Some(min_port) = overrides.min_port {
+            endpoint.min_port = min_port;
+        }
+        if let Some(max_port) = overrides.max_port {
+            endpoint.max_port = max_port;
+        }
+        if overrides.secret_path.is_some() {
+            endpoint.secret_path = overrides.secret_path.clone();
+        }
+        if overrides.secret_token.is_some() {
+            endpoint.secret_token = overrides.secret_token.clone();
+        }
+        endpoint
+    }
+
+    pub fn endpoints(&self) -> HashMap<ApiType, EndpointConfig> {
+        ApiType::ALL
+            .into_iter()
+            .map(|api_type| (api_type, self.resolve(api_type)))
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.discovery.validate()?;
+        for (api_type, overrides) in &self.overrides {
+            let name = api_type.backend().name();
+            let endpoint = self.resolve(*api_type);
+
+            if endpoint.host.is_empty()
+                || endpoint
+                    .host
+                    .chars()
+                    .any(|c| c.is_whitespace() || c == '/' || c == '@')
+            {
+                return Err(format!("{}: invalid host '{}'", name, endpoint.host));
+            }
+            if endpoint.min_port == 0 {
+                return Err(format!("{}: ports must be greater than zero", name));
+            }
+            if endpoint.min_port > endpoint.max_port {
+                return Err(format!(
+                    "{}: minimum port {} is above maximum port {}",
+                    name, endpoint.min_port, endpoint.max_port
+                ));
+            }
+            if endpoint.max_port - endpoint.min_port >= MAX_PORT_SPAN {
+                return Err(format!(
+                    "{}: port range may cover at most {} ports",
+                    name, MAX_PORT_SPAN
+                ));
+            }
+
+            let default = api_type.backend().default_endpoint();
+            if default.secret_path.is_none()
+                && (overrides.secret_path.is_some() || overrides.secret_token.is_some())
+            {
+                return Err(format!("{} does not use an HTTP handshake", name));
+            }
+            if let Some(path) = &endpoint.secret_path {
+                if !path.starts_with('/') || path.chars().any(char::is_whitespace) {
+                    return Err(format!("{}: handshake path must start with '/'", name));
+                }
+            }
+            if endpoint.secret_token.as_deref() == Some("") {
+                return Err(format!("{}: handshake token cannot be empty", name));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_override(api_type: ApiType, overrides: EndpointOverride) -> ExecutorSettings {
+        ExecutorSettings {
+            overrides: HashMap::from([(api_type, overrides)]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn defaults_and_valid_overrides_pass() {
+        assert_eq!(ExecutorSettings::default().validate(), Ok(()));
+        let settings = with_override(
+            ApiType::Hydrogen,
+            EndpointOverride {
+                host: Some(" localhost ".to_string()),
+                min_port: Some(7000),
+                max_port: Some(7000 + MAX_PORT_SPAN - 1),
+                secret_path: Some("/secret".to_string()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(settings.validate(), Ok(()));
+        assert_eq!(settings.resolve(ApiType::Hydrogen).host, "localhost");
+    }
+
+    #[test]
+    fn invalid_overrides_are_rejected() {
+        let invalid = [
+            (
+                ApiType::Hydrogen,
+                EndpointOverride {
+                    host: Some("  ".into()),
+                    ..Default::default()
+                },
+            ),
+            (
+                ApiType::Hydrogen,
+                EndpointOverride {
+                    host: Some("user@host".into()),
+                    ..Default::default()
+                },
+            ),
+            (
+                ApiType::Hydrogen,
+                EndpointOverride {
+                    min_port: Some(0),
+                    ..Default::default()
+                },
+            ),
+            (
+                ApiType::Hydrogen,
+                EndpointOverride {
+                    min_port: Some(7001),
+                    max_port: Some(7000),
+                    ..Default::default()
+                },
+            ),
+            (
+                ApiType::Hydrogen,
+                EndpointOverride {
+                    min_port: Some(1),
+                    max_port: Some(1 + MAX_PORT_SPAN),
+                    ..Default::default()
+                },
+            ),
+            (
+                ApiType::Hydrogen,
+                EndpointOverride {
+                    secret_path: Some("secret".into()),
+                    ..Default::default()
+                },
+            ),
+            (
+                ApiType::Hydrogen,
+                EndpointOverride {
+                    secret_token: Some(String::new()),
+                    ..Default::default()
+                },
+            ),
+            (
+                ApiType::MacSploit,
+                EndpointOverride {
+                    secret_path: Some("/secret".into()),
+                    ..Default::default()
+                },
+            ),
+        ];
+        for (api_type, overrides) in invalid {
+            let settings = with_override(api_type, overrides.clone());
+            assert!(
+                settings.validate().is_err(),
+                "{:?} should be rejected",
+                overrides
+            );
+        }
+    }
+
+    #[test]
+    fn zero_discovery_timeouts_are_rejected() {
+        let settings = ExecutorSettings {
+            discovery: DiscoveryOptions {
+                connect_timeout_ms: 150,
+                handshake_timeout_ms: 0,
+            },
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::paths;
+use crate::uninstall;
+
+// Bumped together with the checksums whenever a vendor publishes a new installer.
+const PINNED_MANIFEST: &str = include_str!("../installers.json");
+const PROGRESS_STEP: u64 = 64 * 1024;
+
+/// Where an executor's installer script is downloaded from and the checksum it must match.
+/// An empty `sha256` means no digest is pinned yet; the script then runs unverified, as it
+/// did before checksums were pinned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallerEntry {
+    pub url: String,
+    pub sha256: String,
+}
+
+/// The installers Comet is willing to run, keyed by executor name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallManifest {
+    pub installers: HashMap<String, InstallerEntry>,
+}
+
+impl InstallManifest {
+    /// The manifest compiled into Comet. It is not read from disk so it can't be swapped out.
+    pub fn pinned() -> Self {
+        serde_json::from_str(PINNED_MANIFEST).expect("Invalid pinned installer manifest")
+    }
+
+    /// The pinned entry for a brand's install URL. URLs that aren't pinned are refused.
+    pub fn for_url(&self, url: &str) -> Result<&InstallerEntry, String> {
+        self.installers
+            .values()
+            .find(|entry| entry.url == url)
+            .ok_or_else(|| format!("No installer is pinned for {}", url))
+    }
+}
+
+/// Sent as the install moves along, tagged by `state`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum InstallProgress {
+    Preparing,
+    Downloading { downloaded: u64, total: Option<u64> },
+    Verifying,
+    Installing,
+    Completed,
+    Error { message: String },
+}
+
+/// `install.log` in the app data dir. Every step of every install is appended to it.
+pub fn log_path() -> PathBuf {
+    paths::app_data_dir().join("logs").join("install.log")
+}
+
+fn log_step(message: &str) {
+    let path = log_path();
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+        let _ = writeln!(file, "[{}] {}", chrono::Local::now().to_rfc3339(), message);
+    }
+}
+
+pub fn read_log() -> Result<String, String> {
+    match fs::read_to_string(log_path()) {
+        Ok(log) => Ok(log),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub(crate) fn is_sha256(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Streams `url` into `target`, reporting progress, and returns the sha256 of what was written.
+pub(crate) async fn download(
+    client: &reqwest::Client,
+    url: &str,
+    target: &Path,
+    on_progress: &mut impl FnMut(InstallProgress),
+) -> Result<String, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Failed to download {}: {}", url, e))?;
+
+    let total = response.content_length();
+    let mut file = File::create(target).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    let mut downloaded = 0;
+    let mut reported = 0;
+    on_progress(InstallProgress::Downloading { downloaded, total });
+
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Failed to download {}: {}", url, e))?;
+        hasher.update(&chunk);
+        file.write_all(&chunk).map_err(|e| e.to_string())?;
+        downloaded += chunk.len() as u64;
+
+        if downloaded - reported >= PROGRESS_STEP {
+            reported = downloaded;
+            on_progress(InstallProgress::Downloading { downloaded, total });
+        }
+    }
+    file.sync_all().map_err(|e| e.to_string())?;
+    if reported != downloaded {
+        on_progress(InstallProgress::Downloading { downloaded, total });
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+async fn run_install(
+    client: &reqwest::Client,
+    entry: &InstallerEntry,
+    script: &Path,
+    run: impl FnOnce(&Path) -> Result<String, String>,
+    on_progress: &mut impl FnMut(InstallProgress),
+) -> Result<(), String> {
+    let pinned = !entry.sha256.is_empty();
+    if pinned && !is_sha256(&entry.sha256) {
+        return Err(format!(
+            "Invalid checksum pinned for {}, refusing to install",
+            entry.url
+        ));
+    }
+
+    let digest = download(client, &entry.url, script, on_progress).await?;
+    log_step("Download finished");
+
+    if pinned {
+        on_progress(InstallProgress::Verifying);
+        if !digest.eq_ignore_ascii_case(&entry.sha256) {
+            return Err(format!(
+                "Installer checksum mismatch: expected {}, got {}",
+                entry.sha256, digest
+            ));
+        }
+        log_step(&format!("Checksum verified ({})", digest));
+    } else {
+        log_step(&format!(
+            "No checksum pinned, running unverified ({})",
+            digest
+        ));
+    }
+
+    on_progress(InstallProgress::Installing);
+    log_step("Running installer");
+    let output = run(script)?;
+    for line in output.lines() {
+        log_step(&format!("installer: {}", line));
+    }
+
+    Ok(())
+}
+
+/// Downloads `entry`, checks it against the pinned checksum, if any, and only then hands the
+/// script to `run`. Nothing is run if the download or the checksum fails.
+pub async fn install(
+    client: &reqwest::Client,
+    entry: &InstallerEntry,
+    run: impl FnOnce(&Path) -> Result<String, String>,
+    mut on_progress: impl FnMut(InstallProgress),
+) -> Result<(), String> {
+    log_step(&format!("Installing from {}", entry.url));
+    on_progress(InstallProgress::Preparing);
+
+    let dir = paths::app_data_dir().join("installers");
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let script = dir.join(format!("{}.sh", uuid::Uuid::new_v4().simple()));
+
+    let result = run_install(client, entry, &script, run, &mut on_progress).await;
+    let _ = fs::remove_file(&script);
+
+    match &result {
+        Ok(()) => {
+            log_step("Installation completed");
+            on_progress(InstallProgress::Completed);
+        }
+        Err(e) => {
+            log_step(&format!("Installation failed: {}", e));
+            on_progress(InstallProgress::Error { message: e.clone() });
+        }
+    }
+    result
+}
+
+/// Runs an installer script as root after asking for the administrator password.
+pub fn run_privileged(script: &Path) -> Result<String, String> {
+    let command = format!(
+        "do shell script \"bash {} 2>&1\" with administrator privileges",
+        uninstall::quote(script)
+    );
+    let output = Command::new("osascript")
+        .args(["-e", &command])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    if !output.status.success() {
+        return Err(format!(
+            "Installation failed: {}{}",
+            stdout,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Pin with `curl -fsSL <url> | shasum -a 256` whenever a vendor publishes a new installer.
+    #[test]
+    fn pinned_checksums_are_unset_or_sha256() {
+        let manifest = InstallManifest::pinned();
+        assert!(!manifest.installers.is_empty());
+        for (executor, entry) in &manifest.installers {
+            assert!(
+                entry.sha256.is_empty() || is_sha256(&entry.sha256),
+                "{} installer has an invalid sha256 pinned: {:?}",
+                executor,
+                entry.sha256
+            );
+        }
+    }
+}
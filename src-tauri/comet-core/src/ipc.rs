@@ -0,0 +1,321 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::Notify;
+
+use crate::execution_history::{self, ExecutionRecord};
+use crate::{auto_execute, paths, tabs, workspace, ApiType, AppState};
+
+const CONTROL_DIR: &str = "control";
+const SOCKET_NAME: &str = "comet.sock";
+const TOKEN_NAME: &str = "control.token";
+const HISTORY_LIMIT: usize = 100;
+
+/// A control command, sent as one JSON object per line together with the token from
+/// [`token_path`], e.g. `{"token":"…","command":"execute","script":"print(1)"}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Request {
+    Status,
+    Reconnect,
+    SetApiType {
+        api_type: ApiType,
+    },
+    /// Runs a script on the selected executor and records it in the execution history.
+    Execute {
+        script: String,
+    },
+    ListWorkspaces,
+    /// Lists the tabs of a workspace, the active one if `workspace_id` is omitted.
+    ListTabs {
+        #[serde(default)]
+        workspace_id: Option<String>,
+    },
+    History,
+    SyncAutoExecute,
+    /// Stops `comet-daemon`. The app refuses it, so clients can't close its window.
+    Shutdown,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    token: String,
+    #[serde(flatten)]
+    request: Request,
+}
+
+/// The reply to a [`Request`], also one JSON object per line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Response {
+    fn from_result(result: Result<Value, String>) -> Self {
+        match result {
+            Ok(value) => Self {
+                ok: true,
+                result: Some(value),
+                error: None,
+            },
+            Err(e) => Self {
+                ok: false,
+                result: None,
+                error: Some(e),
+            },
+        }
+    }
+}
+
+/// A bound control socket and the token clients must present.
+pub struct ControlSocket {
+    listener: UnixListener,
+    path: PathBuf,
+    token: String,
+}
+
+/// The folder holding the socket and token, `control` in the app data dir.
+pub fn control_dir() -> PathBuf {
+    paths::app_data_dir().join(CONTROL_DIR)
+}
+
+/// Where the control socket lives: `comet.sock` in [`control_dir`].
+pub fn socket_path() -> PathBuf {
+    control_dir().join(SOCKET_NAME)
+}
+
+/// Where the running instance writes its token: `control.token` in [`control_dir`].
+pub fn token_path() -> PathBuf {
+    control_dir().join(TOKEN_NAME)
+}
+
+fn to_value(value: impl Serialize) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| e.to_string())
+}
+
+async fn handle(
+    state: &AppState,
+    request: Request,
+    shutdown: Option<&Notify>,
+) -> Result<Value, String> {
+    match request {
+        Request::Status => to_value(state.status().await),
+        Request::Reconnect => to_value(state.refresh_connection().await),
+        Request::SetApiType { api_type } => to_value(state.set_api_type(api_type).await),
+        Request::Execute { script } => {
+            let result = state.execute(&script).await;
+            let error = match &result {
+                Ok(result) => result.error_message(),
+                Err(e) => Some(e.to_string()),
+            };
+            let record = ExecutionRecord {
+                id: uuid::Uuid::new_v4().to_string(),
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                content: script,
+                success: error.is_none(),
+                error,
+            };
+            if let Err(e) = execution_history::save_execution_record(record, HISTORY_LIMIT) {
+                eprintln!("Failed to record execution: {}", e);
+            }
+            to_value(result.map_err(|e| e.to_string())?)
+        }
+        Request::ListWorkspaces => to_value(workspace::load_workspaces()?),
+        Request::ListTabs { workspace_id } => {
+            let workspace_id = match workspace_id {
+                Some(workspace_id) => workspace_id,
+                None => workspace::load_workspaces()?
+                    .active_workspace
+                    .ok_or("No active workspace")?,
+            };
+            to_value(tabs::load_tabs(&workspace_id)?)
+        }
+        Request::History => to_value(execution_history::load_execution_history()?),
+        Request::SyncAutoExecute => {
+            let api_type = state.status().await.api_type;
+            to_value(auto_execute::sync_auto_execute(api_type)?)
+        }
+        Request::Shutdown => {
+            let shutdown = shutdown.ok_or("Shutdown is only available in comet-daemon")?;
+            shutdown.notify_one();
+            Ok(Value::Null)
+        }
+    }
+}
+
+async fn serve_connection(
+    stream: UnixStream,
+    token: Arc<str>,
+    state: AppState,
+    shutdown: Option<Arc<Notify>>,
+) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Envelope>(&line) {
+            Ok(envelope) if token_matches(&envelope.token, &token) => {
+                Response::from_result(handle(&state, envelope.request, shutdown.as_deref()).await)
+            }
+            Ok(_) => Response::from_result(Err("Invalid token".to_string())),
+            Err(e) => Response::from_result(Err(format!("Invalid request: {}", e))),
+        };
+
+        let Ok(mut reply) = serde_json::to_string(&response) else {
+            break;
+        };
+        reply.push('\n');
+        if writer.write_all(reply.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+// Looks at every byte, so how long a wrong token takes to reject says nothing about it.
+fn token_matches(token: &str, expected: &str) -> bool {
+    token.len() == expected.len()
+        && token
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+// Creates the control folder, or tightens an existing one, so only the current user can reach
+// anything inside it from the moment it is created.
+fn create_private_dir(dir: &Path) -> Result<(), String> {
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(dir)
+        .and_then(|_| fs::set_permissions(dir, fs::Permissions::from_mode(0o700)))
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))
+}
+
+// The file is created with owner-only permissions before the secret is written to it.
+fn write_private(path: &Path, content: &str) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != ErrorKind::NotFound => {
+            return Err(format!("Failed to remove {}: {}", path.display(), e))
+        }
+        _ => {}
+    }
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Binds the control socket, replacing a stale one left by a crashed process, and writes a
+/// fresh token that only the current user can read.
+pub async fn bind() -> Result<ControlSocket, String> {
+    create_private_dir(&control_dir())?;
+    let path = socket_path();
+    if path.exists() {
+        if UnixStream::connect(&path).await.is_ok() {
+            return Err("Comet is already running".to_string());
+        }
+        fs::remove_file(&path).map_err(|e| format!("Failed to remove stale socket: {}", e))?;
+    }
+
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    write_private(&token_path(), &token)?;
+
+    let listener =
+        UnixListener::bind(&path).map_err(|e| format!("Failed to bind socket: {}", e))?;
+
+    Ok(ControlSocket {
+        listener,
+        path,
+        token,
+    })
+}
+
+/// Answers control requests until a `shutdown` request arrives or `shutdown` is notified,
+/// then removes the socket and token files. Without `shutdown`, `shutdown` requests are refused
+/// and the socket is served for as long as the process runs.
+pub async fn serve(socket: ControlSocket, state: AppState, shutdown: Option<Arc<Notify>>) {
+    let token: Arc<str> = socket.token.into();
+    let stop = shutdown.clone();
+    let stopped = async move {
+        match stop {
+            Some(stop) => stop.notified().await,
+            None => std::future::pending().await,
+        }
+    };
+    tokio::pin!(stopped);
+
+    loop {
+        tokio::select! {
+            accepted = socket.listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    tokio::spawn(serve_connection(
+                        stream,
+                        token.clone(),
+                        state.clone(),
+                        shutdown.clone(),
+                    ));
+                }
+                Err(e) => eprintln!("Control socket error: {}", e),
+            },
+            _ = &mut stopped => break,
+        }
+    }
+
+    let _ = fs::remove_file(&socket.path);
+    let _ = fs::remove_file(token_path());
+}
+
+/// Sends one request to the running Comet and waits for the reply.
+pub async fn send(request: &Request) -> Result<Value, String> {
+    let token = fs::read_to_string(token_path())
+        .map_err(|_| "Comet is not running (no control token)".to_string())?;
+    let stream = UnixStream::connect(socket_path())
+        .await
+        .map_err(|e| format!("Comet is not running ({})", e))?;
+    let (reader, mut writer) = stream.into_split();
+
+    let envelope = Envelope {
+        token: token.trim().to_string(),
+        request: request.clone(),
+    };
+    let mut line = serde_json::to_string(&envelope).map_err(|e| e.to_string())?;
+    line.push('\n');
+    writer
+        .write_all(line.as_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let reply = BufReader::new(reader)
+        .lines()
+        .next_line()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Comet closed the connection")?;
+    let response: Response = serde_json::from_str(&reply).map_err(|e| e.to_string())?;
+
+    if response.ok {
+        Ok(response.result.unwrap_or(Value::Null))
+    } else {
+        Err(response
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string()))
+    }
+}
@@ -0,0 +1,46 @@
+//! Executor connection, workspaces, tabs, auto-execute, execution history and Roblox log
+//! watching for Comet, independent of any UI.
+//!
+//! [`AppState`] owns the executor connection; everything else works on files in
+//! [`paths::app_data_dir`] and the executors' own folders. [`daemon`] runs it all without a
+//! window, controlled through [`ipc`].
+
+pub mod auto_execute;
+pub mod backend;
+pub mod brand;
+pub mod config;
+pub mod connection;
+pub mod daemon;
+pub mod detector;
+pub mod discovery;
+pub mod execution_history;
+pub mod executor_settings;
+pub mod installer;
+pub mod ipc;
+pub mod logs;
+#[cfg(any(test, feature = "test-support"))]
+pub mod mock_executor;
+pub mod paths;
+pub mod persist;
+pub mod process;
+pub mod revisions;
+pub mod scripts;
+pub mod settings;
+pub mod state;
+pub mod supervisor;
+pub mod tabs;
+pub mod uninstall;
+pub mod updater;
+pub mod workspace;
+
+pub use state::{AppState, ConnectionStatus};
+
+/// The executor protocol Comet talks to.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum ApiType {
+    #[default]
+    Hydrogen,
+    MacSploit,
+}
@@ -0,0 +1,158 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Seek};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::paths;
+
+/// Set while a watcher is following the Roblox log; clearing it stops the watcher.
+pub static WATCHING: AtomicBool = AtomicBool::new(false);
+static LAST_DISCONNECT_TIME: AtomicU64 = AtomicU64::new(0);
+
+const DISCONNECT_PATTERN: &str = "[FLog::Network] Connection lost";
+const COOLDOWN_DURATION: u64 = 5;
+
+/// Something seen in the Roblox client log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogEvent {
+    Line(String),
+    /// The client lost its connection to the game server. Rate limited to one per cooldown.
+    Disconnected,
+}
+
+fn get_current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn check_for_disconnect(content: &str) -> bool {
+    let current_time = get_current_timestamp();
+    let last_disconnect = LAST_DISCONNECT_TIME.load(Ordering::SeqCst);
+
+    if current_time.saturating_sub(last_disconnect) < COOLDOWN_DURATION {
+        return false;
+    }
+
+    if content.contains(DISCONNECT_PATTERN) {
+        LAST_DISCONNECT_TIME.store(current_time, Ordering::SeqCst);
+        return true;
+    }
+
+    false
+}
+
+fn process_log_line(line: &str, on_event: &mut impl FnMut(LogEvent)) {
+    if line.trim().is_empty() {
+        return;
+    }
+
+    on_event(LogEvent::Line(line.trim().to_string()));
+    if check_for_disconnect(line) {
+        on_event(LogEvent::Disconnected);
+    }
+}
+
+pub fn find_latest_log_file() -> Option<PathBuf> {
+    let home = paths::home_dir().ok()?;
+    let log_dir = home.join("Library").join("Logs").join("Roblox");
+
+    if !log_dir.exists() {
+        return None;
+    }
+
+    let latest = fs::read_dir(log_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "log"))
+        .max_by_key(|entry| entry.metadata().ok().and_then(|m| m.modified().ok()));
+    latest.map(|entry| entry.path())
+}
+
+/// Follows the newest Roblox log until [`WATCHING`] is cleared, switching to newer logs as they appear.
+pub fn watch_log_file(
+    mut current_log_path: PathBuf,
+    mut on_event: impl FnMut(LogEvent),
+) -> io::Result<()> {
+    let mut file = File::open(&current_log_path)?;
+    let mut reader = BufReader::new(&file);
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? > 0 {
+        process_log_line(&line, &mut on_event);
+        line.clear();
+    }
+
+    let mut last_position = reader.stream_position()?;
+    let mut last_check = std::time::Instant::now();
+
+    while WATCHING.load(Ordering::SeqCst) {
+        if last_check.elapsed() >= Duration::from_secs(1) {
+            if let Some(new_path) = find_latest_log_file() {
+                if new_path != current_log_path {
+                    current_log_path = new_path;
+                    file = File::open(&current_log_path)?;
+                    reader = BufReader::new(&file);
+
+                    while reader.read_line(&mut line)? > 0 {
+                        process_log_line(&line, &mut on_event);
+                        line.clear();
+                    }
+
+                    last_position = reader.stream_position()?;
+                }
+            }
+            last_check = std::time::Instant::now();
+        }
+
+        let metadata = fs::metadata(&current_log_path)?;
+        let len = metadata.len();
+
+        if len < last_position {
+            file = File::open(&current_log_path)?;
+            reader = BufReader::new(&file);
+            last_position = 0;
+        }
+
+        if len > last_position {
+            reader.seek(io::SeekFrom::Start(last_position))?;
+
+            while reader.read_line(&mut line)? > 0 {
+                process_log_line(&line, &mut on_event);
+                line.clear();
+            }
+
+            last_position = reader.stream_position()?;
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    Ok(())
+}
+
+/// Starts following the newest Roblox log on a background thread. Does nothing if already watching.
+pub fn start_watching(on_event: impl FnMut(LogEvent) + Send + 'static) -> Result<(), String> {
+    if WATCHING.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let log_path = find_latest_log_file().ok_or("No log file found")?;
+    WATCHING.store(true, Ordering::SeqCst);
+
+    thread::spawn(move || {
+        if let Err(e) = watch_log_file(log_path, on_event) {
+            eprintln!("Error watching log file: {}", e);
+        }
+        WATCHING.store(false, Ordering::SeqCst);
+    });
+
+    Ok(())
+}
+
+pub fn stop_watching() {
+    WATCHING.store(false, Ordering::SeqCst);
+}
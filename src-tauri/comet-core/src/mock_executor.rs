@@ -0,0 +1,519 @@
+use std::collections::HashMap;
+use std::io;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+const HEADER_LEN: usize = 16;
+const MAX_HEADERS_LEN: usize = 64 * 1024;
+const MAX_BODY_LEN: u64 = 16 * 1024 * 1024;
+const MESSAGE_PRINT: u8 = 1;
+const MESSAGE_ERROR: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Secret,
+    Execute,
+    MacSploit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultKind {
+    Timeout,
+    Drop,
+    Status(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    Always,
+    Nth(usize),
+    From(usize),
+    Every(usize),
+}
+
+impl Trigger {
+    fn matches(&self, count: usize) -> bool {
+        match *self {
+            Trigger::Always => true,
+            Trigger::Nth(n) => count == n,
+            Trigger::From(n) => count >= n,
+            Trigger::Every(n) => count.is_multiple_of(n),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fault {
+    pub stage: Stage,
+    pub kind: FaultKind,
+    pub trigger: Trigger,
+}
+
+// Parses `<stage>:<fault>[@<trigger>]`, e.g. `execute:500@2`, `secret:timeout@3+`,
+// `macsploit:drop@%2`. Stages are secret, execute and macsploit; faults are timeout,
+// drop or an HTTP status (an error frame on MacSploit).
+impl FromStr for Fault {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let (rule, trigger) = match spec.split_once('@') {
+            Some((rule, trigger)) => (rule, Some(trigger)),
+            None => (spec, None),
+        };
+        let (stage, kind) = rule
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid fault '{}': expected <stage>:<fault>", spec))?;
+
+        let stage = match stage {
+            "secret" => Stage::Secret,
+            "execute" => Stage::Execute,
+            "macsploit" => Stage::MacSploit,
+            _ => return Err(format!("Unknown fault stage '{}'", stage)),
+        };
+        let kind = match kind {
+            "timeout" => FaultKind::Timeout,
+            "drop" => FaultKind::Drop,
+            code => FaultKind::Status(
+                code.parse()
+                    .ok()
+                    .filter(|code| (100..600).contains(code))
+                    .ok_or_else(|| format!("Unknown fault '{}'", code))?,
+            ),
+        };
+        let count = |value: &str| {
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| format!("Invalid fault trigger '{}'", value))
+        };
+        let trigger = match trigger {
+            None => Trigger::Always,
+            Some(every) if every.starts_with('%') => Trigger::Every(count(&every[1..])?),
+            Some(from) if from.ends_with('+') => Trigger::From(count(&from[..from.len() - 1])?),
+            Some(nth) => Trigger::Nth(count(nth)?),
+        };
+
+        Ok(Fault {
+            stage,
+            kind,
+            trigger,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MockConfig {
+    pub host: String,
+    pub hydrogen_ports: Vec<u16>,
+    pub macsploit_ports: Vec<u16>,
+    pub secret_path: String,
+    pub secret_token: String,
+    pub faults: Vec<Fault>,
+    pub stall: Duration,
+    pub verbose: bool,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            hydrogen_ports: vec![6969],
+            macsploit_ports: vec![5553],
+            secret_path: "/secret".to_string(),
+            secret_token: "0xdeadbeef".to_string(),
+            faults: Vec::new(),
+            stall: Duration::from_secs(30),
+            verbose: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedScript {
+    pub stage: Stage,
+    pub port: u16,
+    pub script: String,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    counters: Mutex<HashMap<Stage, usize>>,
+    received: Mutex<Vec<ReceivedScript>>,
+    connections: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct Server {
+    config: MockConfig,
+    shared: Shared,
+}
+
+impl Server {
+    fn log(&self, port: u16, message: impl AsRef<str>) {
+        if self.config.verbose {
+            println!(
+                "[{}] :{} {}",
+                chrono::Local::now().format("%H:%M:%S%.3f"),
+                port,
+                message.as_ref()
+            );
+        }
+    }
+
+    // Counts the request and returns the first fault whose trigger matches it.
+    fn fault(&self, stage: Stage) -> Option<FaultKind> {
+        let count = {
+            let mut counters = self.shared.counters.lock().unwrap();
+            let count = counters.entry(stage).or_default();
+            *count += 1;
+            *count
+        };
+        self.config
+            .faults
+            .iter()
+            .find(|fault| fault.stage == stage && fault.trigger.matches(count))
+            .map(|fault| fault.kind)
+    }
+
+    fn record(&self, stage: Stage, port: u16, script: &[u8]) {
+        let script = String::from_utf8_lossy(script).into_owned();
+        self.log(port, format!("{:?} received {} bytes", stage, script.len()));
+        if self.config.verbose {
+            for line in script.lines() {
+                println!("    | {}", line);
+            }
+        }
+        self.shared.received.lock().unwrap().push(ReceivedScript {
+            stage,
+            port,
+            script,
+        });
+    }
+
+    async fn serve_http(self: Arc<Self>, port: u16, mut stream: TcpStream) -> io::Result<()> {
+        let Some((method, path, body)) = read_request(&mut stream).await? else {
+            return Ok(());
+        };
+        self.log(port, format!("{} {}", method, path));
+
+        let (stage, status, response) = match (method.as_str(), path.as_str()) {
+            ("GET", path) if path == self.config.secret_path => {
+                (Stage::Secret, 200, self.config.secret_token.clone())
+            }
+            ("POST", "/execute") => (Stage::Execute, 200, String::new()),
+            _ => return write_response(&mut stream, 404, "Not Found").await,
+        };
+
+        match self.fault(stage) {
+            Some(FaultKind::Timeout) => {
+                self.log(port, "injecting timeout");
+                tokio::time::sleep(self.config.stall).await;
+                Ok(())
+            }
+            Some(FaultKind::Drop) => {
+                self.log(port, "dropping connection");
+                Ok(())
+            }
+            Some(FaultKind::Status(code)) => {
+                self.log(port, format!("injecting HTTP {}", code));
+                write_response(&mut stream, code, "Injected failure").await
+            }
+            None => {
+                if stage == Stage::Execute {
+                    self.record(stage, port, &body);
+                }
+                write_response(&mut stream, status, &response).await
+            }
+        }
+    }
+
+    async fn serve_macsploit(self: Arc<Self>, port: u16, mut stream: TcpStream) -> io::Result<()> {
+        self.log(port, "MacSploit client connected");
+        loop {
+            let mut header = [0u8; HEADER_LEN];
+            if stream.read_exact(&mut header).await.is_err() {
+                self.log(port, "MacSploit client disconnected");
+                return Ok(());
+            }
+
+            let mut length = [0u8; 8];
+            length.copy_from_slice(&header[8..16]);
+            let length = u64::from_le_bytes(length);
+            if length > MAX_BODY_LEN {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Frame too large",
+                ));
+            }
+            let mut payload = vec![0u8; length as usize];
+            stream.read_exact(&mut payload).await?;
+
+            match self.fault(Stage::MacSploit) {
+                Some(FaultKind::Timeout) => {
+                    self.log(port, "injecting timeout");
+                    tokio::time::sleep(self.config.stall).await;
+                }
+                Some(FaultKind::Drop) => {
+                    self.log(port, "dropping connection");
+                    return Ok(());
+                }
+                Some(FaultKind::Status(code)) => {
+                    self.log(port, format!("injecting error frame ({})", code));
+                    let message = format!("Injected failure {}", code);
+                    stream
+                        .write_all(&encode_frame(MESSAGE_ERROR, message.as_bytes()))
+                        .await?;
+                }
+                None => {
+                    self.record(Stage::MacSploit, port, &payload);
+                    let message = format!("Executed {} bytes", payload.len());
+                    stream
+                        .write_all(&encode_frame(MESSAGE_PRINT, message.as_bytes()))
+                        .await?;
+                }
+            }
+        }
+    }
+}
+
+fn encode_frame(kind: u8, payload: &[u8]) -> Vec<u8> {
+    let mut buffer = vec![0u8; HEADER_LEN + payload.len()];
+    buffer[0] = kind;
+    buffer[8..16].copy_from_slice(&(payload.len() as u64).to_le_bytes());
+    buffer[HEADER_LEN..].copy_from_slice(payload);
+    buffer
+}
+
+async fn read_request<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> io::Result<Option<(String, String, Vec<u8>)>> {
+    let mut buffer = Vec::new();
+    let header_end = loop {
+        if let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end;
+        }
+        if buffer.len() > MAX_HEADERS_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Headers too large",
+            ));
+        }
+        let mut chunk = [0u8; 4096];
+        let read = reader.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).into_owned();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+    if content_length > MAX_BODY_LEN {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "Body too large"));
+    }
+
+    let mut body = buffer[header_end + 4..].to_vec();
+    if (body.len() as u64) < content_length {
+        let start = body.len();
+        body.resize(content_length as usize, 0);
+        reader.read_exact(&mut body[start..]).await?;
+    }
+    body.truncate(content_length as usize);
+
+    Ok(Some((method, path, body)))
+}
+
+async fn write_response(stream: &mut TcpStream, status: u16, body: &str) -> io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Status",
+    };
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// A fake Hydrogen/MacSploit executor listening on local ports. Port 0 binds an ephemeral port.
+#[derive(Debug)]
+pub struct MockExecutor {
+    server: Arc<Server>,
+    hydrogen_ports: Vec<u16>,
+    macsploit_ports: Vec<u16>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockExecutor {
+    pub async fn start(config: MockConfig) -> io::Result<Self> {
+        let server = Arc::new(Server {
+            config,
+            shared: Shared::default(),
+        });
+        let mut executor = Self {
+            server: server.clone(),
+            hydrogen_ports: Vec::new(),
+            macsploit_ports: Vec::new(),
+            tasks: Vec::new(),
+        };
+
+        for &port in &server.config.hydrogen_ports {
+            let (port, task) = executor.listen(port, false).await?;
+            executor.hydrogen_ports.push(port);
+            executor.tasks.push(task);
+        }
+        for &port in &server.config.macsploit_ports {
+            let (port, task) = executor.listen(port, true).await?;
+            executor.macsploit_ports.push(port);
+            executor.tasks.push(task);
+        }
+
+        Ok(executor)
+    }
+
+    async fn listen(&self, port: u16, macsploit: bool) -> io::Result<(u16, JoinHandle<()>)> {
+        let listener = TcpListener::bind((self.server.config.host.as_str(), port)).await?;
+        let port = listener.local_addr()?.port();
+        let server = self.server.clone();
+        server.log(
+            port,
+            format!(
+                "listening for {}",
+                if macsploit { "MacSploit" } else { "Hydrogen" }
+            ),
+        );
+
+        let task = tokio::spawn(async move {
+            while let Ok((stream, peer)) = listener.accept().await {
+                server.shared.connections.fetch_add(1, Ordering::SeqCst);
+                let server = server.clone();
+                tokio::spawn(async move {
+                    let result = if macsploit {
+                        server.clone().serve_macsploit(port, stream).await
+                    } else {
+                        server.clone().serve_http(port, stream).await
+                    };
+                    if let Err(e) = result {
+                        server.log(port, format!("connection from {}: {}", peer, e));
+                    }
+                });
+            }
+        });
+
+        Ok((port, task))
+    }
+
+    pub fn hydrogen_ports(&self) -> &[u16] {
+        &self.hydrogen_ports
+    }
+
+    pub fn macsploit_ports(&self) -> &[u16] {
+        &self.macsploit_ports
+    }
+
+    pub fn received(&self) -> Vec<ReceivedScript> {
+        self.server.shared.received.lock().unwrap().clone()
+    }
+
+    pub fn connections(&self) -> usize {
+        self.server.shared.connections.load(Ordering::SeqCst)
+    }
+
+    pub fn shutdown(&self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for MockExecutor {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn faults_parse_stage_kind_and_trigger() {
+        assert_eq!(
+            "execute:500@2".parse(),
+            Ok(Fault {
+                stage: Stage::Execute,
+                kind: FaultKind::Status(500),
+                trigger: Trigger::Nth(2),
+            })
+        );
+        assert_eq!(
+            "secret:timeout@3+".parse(),
+            Ok(Fault {
+                stage: Stage::Secret,
+                kind: FaultKind::Timeout,
+                trigger: Trigger::From(3),
+            })
+        );
+        assert_eq!(
+            "macsploit:drop@%2".parse(),
+            Ok(Fault {
+                stage: Stage::MacSploit,
+                kind: FaultKind::Drop,
+                trigger: Trigger::Every(2),
+            })
+        );
+        assert_eq!(
+            "execute:drop".parse::<Fault>().map(|fault| fault.trigger),
+            Ok(Trigger::Always)
+        );
+    }
+
+    #[test]
+    fn invalid_faults_are_rejected() {
+        for spec in [
+            "execute",
+            "install:500",
+            "execute:crash",
+            "execute:99",
+            "execute:600",
+            "execute:500@0",
+            "execute:500@%0",
+            "execute:500@x+",
+        ] {
+            assert!(spec.parse::<Fault>().is_err(), "{} should not parse", spec);
+        }
+    }
+
+    #[test]
+    fn triggers_match_their_request_counts() {
+        assert!(Trigger::Always.matches(7));
+        assert!(Trigger::Nth(2).matches(2));
+        assert!(!Trigger::Nth(2).matches(3));
+        assert!(!Trigger::From(3).matches(2));
+        assert!(Trigger::From(3).matches(4));
+        assert!(Trigger::Every(2).matches(4));
+        assert!(!Trigger::Every(2).matches(5));
+    }
+}
@@ -0,0 +1,46 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+const APP_DIR: &str = "com.comet.dev";
+
+static DATA_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Redirects all persisted state (and the executor home and app folders) under `root`.
+/// Used by the test harness; `None` restores the real locations.
+#[cfg(any(test, feature = "test-support"))]
+pub fn set_data_root(root: Option<PathBuf>) {
+    *DATA_ROOT.write().unwrap() = root;
+}
+
+fn data_root() -> Option<PathBuf> {
+    DATA_ROOT.read().unwrap().clone()
+}
+
+/// Comet's data folder (`~/Library/Application Support/com.comet.dev`), created on demand.
+pub fn app_data_dir() -> PathBuf {
+    let path = match data_root() {
+        Some(root) => root.join(APP_DIR),
+        None => dirs::config_dir()
+            .expect("Failed to get Application Support directory")
+            .join(APP_DIR),
+    };
+    fs::create_dir_all(&path).expect("Failed to create directory");
+    path
+}
+
+/// The user's home folder, where executors keep their own data.
+pub fn home_dir() -> Result<PathBuf, String> {
+    match data_root() {
+        Some(root) => Ok(root.join("home")),
+        None => dirs::home_dir().ok_or_else(|| "Could not find home directory".to_string()),
+    }
+}
+
+/// Where macOS apps live (`/Applications`), holding Roblox and the executors' own apps.
+pub fn applications_dir() -> PathBuf {
+    match data_root() {
+        Some(root) => root.join("Applications"),
+        None => PathBuf::from("/Applications"),
+    }
+}
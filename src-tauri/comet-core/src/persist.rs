@@ -0,0 +1,123 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Where [`write`] keeps the previous copy of `path`.
+pub fn backup_path(path: &Path) -> PathBuf {
+    with_suffix(path, ".bak")
+}
+
+fn parses_as_json(path: &Path) -> bool {
+    File::open(path)
+        .ok()
+        .and_then(|file| {
+            serde_json::from_reader::<_, serde::de::IgnoredAny>(BufReader::new(file)).ok()
+        })
+        .is_some()
+}
+
+// Moves the current file aside as the backup by linking, so the file itself stays in place
+// until the new one is renamed over it.
+fn back_up(path: &Path) -> Result<(), String> {
+    let backup = backup_path(path);
+    let _ = fs::remove_file(&backup);
+    fs::hard_link(path, &backup)
+        .or_else(|_| fs::copy(path, &backup).map(|_| ()))
+        .map_err(|e| format!("Failed to back up {}: {}", path.display(), e))
+}
+
+fn replace(path: &Path, content: &[u8], keep_backup: bool) -> Result<(), String> {
+    let temp = with_suffix(path, ".tmp");
+    let written = File::create(&temp)
+        .and_then(|mut file| {
+            file.write_all(content)?;
+            file.sync_all()
+        })
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&temp);
+        return Err(e);
+    }
+
+    if keep_backup {
+        back_up(path)?;
+    }
+    fs::rename(&temp, path).map_err(|e| format!("Failed to replace {}: {}", path.display(), e))?;
+
+    // The rename itself is only durable once the directory is synced.
+    if let Some(dir) = path.parent() {
+        let _ = File::open(dir).and_then(|dir| dir.sync_all());
+    }
+    Ok(())
+}
+
+/// Replaces `path` atomically: `content` goes to a temp file that is synced to disk and then
+/// renamed over `path`, so a crash leaves either the old or the new file, never half of one.
+/// The old file is kept as `<path>.bak`.
+pub fn write(path: &Path, content: impl AsRef<[u8]>) -> Result<(), String> {
+    replace(path, content.as_ref(), path.exists())
+}
+
+/// Like [`write`], for pretty-printed JSON. The old file only replaces the backup if it still
+/// parses, so a corrupt file never overwrites the last good copy.
+pub fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    let content = serde_json::to_vec_pretty(value).map_err(|e| e.to_string())?;
+    replace(path, &content, parses_as_json(path))
+}
+
+/// Reads `path` with `parse`, falling back to `<path>.bak` if that fails. `None` if `path`
+/// doesn't exist.
+pub fn read_with<T>(
+    path: &Path,
+    parse: impl Fn(&Path) -> Result<T, String>,
+) -> Result<Option<T>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let error = match parse(path) {
+        Ok(value) => return Ok(Some(value)),
+        Err(e) => e,
+    };
+    let backup = backup_path(path);
+    match parse(&backup) {
+        Ok(value) => {
+            eprintln!("Restored {} from its backup: {}", path.display(), error);
+            Ok(Some(value))
+        }
+        Err(_) => Err(error),
+    }
+}
+
+/// Reads and parses the JSON in `path`, falling back to `<path>.bak` if it is unreadable or
+/// doesn't parse. `None` if `path` doesn't exist.
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, String> {
+    read_with(path, |path| {
+        let file =
+            File::open(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    })
+}
+
+/// Deletes `path` together with its backup.
+pub fn remove(path: &Path) -> Result<(), String> {
+    for path in [backup_path(path), path.to_path_buf()] {
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                return Err(format!("Failed to remove {}: {}", path.display(), e))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use serde::Serialize;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::AppState;
+
+const ROBLOX_PROCESS: &str = "RobloxPlayer";
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A running Roblox player.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RobloxProcess {
+    pub pid: u32,
+    /// Unix time in milliseconds.
+    pub started_at: i64,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+}
+
+/// A Roblox player starting or stopping.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProcessEvent {
+    Launched { process: RobloxProcess },
+    Exited { pid: u32 },
+}
+
+// `ps` prints elapsed time as [[dd-]hh:]mm:ss.
+fn parse_elapsed(elapsed: &str) -> Option<i64> {
+    let (days, clock) = match elapsed.split_once('-') {
+        Some((days, clock)) => (days.parse::<i64>().ok()?, clock),
+        None => (0, elapsed),
+    };
+
+    let mut seconds = 0;
+    for part in clock.split(':') {
+        seconds = seconds * 60 + part.parse::<i64>().ok()?;
+    }
+    Some(days * 86_400 + seconds)
+}
+
+/// Picks the Roblox players out of `ps -axo pid=,etime=,%cpu=,rss=,comm=` output.
+pub fn parse_ps_output(output: &str, now_ms: i64) -> Vec<RobloxProcess> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let pid = fields.next()?.parse().ok()?;
+            let elapsed = parse_elapsed(fields.next()?)?;
+            let cpu_percent = fields.next()?.parse().ok()?;
+            let rss_kb = fields.next()?.parse::<u64>().ok()?;
+            let command = fields.collect::<Vec<_>>().join(" ");
+
+            let name = command.rsplit('/').next().unwrap_or(&command);
+            (name == ROBLOX_PROCESS).then_some(RobloxProcess {
+                pid,
+                started_at: now_ms - elapsed * 1000,
+                cpu_percent,
+                memory_bytes: rss_kb * 1024,
+            })
+        })
+        .collect()
+}
+
+/// Lists running Roblox players.
+pub fn list_roblox_processes() -> Result<Vec<RobloxProcess>, String> {
+    let output = Command::new("ps")
+        .args(["-axo", "pid=,etime=,%cpu=,rss=,comm="])
+        .output()
+        .map_err(|e| format!("Failed to run ps: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!("ps exited with {}", output.status));
+    }
+
+    Ok(parse_ps_output(
+        &String::from_utf8_lossy(&output.stdout),
+        chrono::Utc::now().timestamp_millis(),
+    ))
+}
+
+/// Polls for Roblox players and feeds them into `state`'s connection status.
+pub async fn run(state: AppState) {
+    loop {
+        match tokio::task::spawn_blocking(list_roblox_processes).await {
+            Ok(Ok(processes)) => state.set_roblox_processes(processes).await,
+            Ok(Err(e)) => eprintln!("Failed to list Roblox processes: {}", e),
+            Err(_) => {}
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_elapsed_accepts_every_ps_format() {
+        assert_eq!(parse_elapsed("00:07"), Some(7));
+        assert_eq!(parse_elapsed("05:10"), Some(310));
+        assert_eq!(parse_elapsed("02:03:04"), Some(7_384));
+        assert_eq!(parse_elapsed("01-02:03:04"), Some(93_784));
+        assert_eq!(parse_elapsed("1:x"), None);
+        assert_eq!(parse_elapsed("x-00:01"), None);
+    }
+
+    #[test]
+    fn parse_ps_output_keeps_only_roblox_players() {
+        let output = "  311 01-02:03:04   0.0   1024 /sbin/launchd
+  812    05:10  42.5 524288 /Applications/Roblox.app/Contents/MacOS/RobloxPlayer
+  813    00:01   0.5   4096 /Users/me/Roblox Copy.app/Contents/MacOS/RobloxPlayer
+  900 00:02   1.0   2048 /Applications/Roblox.app/Contents/MacOS/RobloxPlayerInstaller
+  bad 00:02   1.0   2048 /Applications/Roblox.app/Contents/MacOS/RobloxPlayer
+";
+        assert_eq!(
+            parse_ps_output(output, 1_000_000),
+            vec![
+                RobloxProcess {
+                    pid: 812,
+                    started_at: 1_000_000 - 310_000,
+                    cpu_percent: 42.5,
+                    memory_bytes: 524_288 * 1024,
+                },
+                RobloxProcess {
+                    pid: 813,
+                    started_at: 999_000,
+                    cpu_percent: 0.5,
+                    memory_bytes: 4_096 * 1024,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::persist;
+use crate::settings::{self, HistorySettings};
+use crate::tabs;
+use crate::workspace::get_workspace_history_dir;
+
+const INDEX_FILE: &str = "index.json";
+const DAY_MILLIS: i64 = 24 * 60 * 60 * 1000;
+// Above this many old × new lines, the changed middle of a diff is shown as replaced instead
+// of matched line by line.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// One saved version of a tab. Times are Unix milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revision {
+    pub id: u64,
+    pub created_at: i64,
+    /// The tab's title when the revision was saved.
+    pub title: String,
+    /// The size of the content in bytes, before compression.
+    pub size: usize,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffKind {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+/// One line of a diff, numbered in the revisions it appears in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+    pub content: String,
+}
+
+// Serializes index updates, which every tab save makes.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn tab_history_dir(workspace_id: &str, tab_id: &str) -> Result<PathBuf, String> {
+    tabs::validate_tab_id(tab_id)?;
+    Ok(get_workspace_history_dir(workspace_id).join(tab_id))
+}
+
+fn snapshot_path(dir: &Path, revision: u64) -> PathBuf {
+    dir.join(format!("{}.lua.gz", revision))
+}
+
+fn read_index(dir: &Path) -> Result<Vec<Revision>, String> {
+    Ok(persist::read_json(&dir.join(INDEX_FILE))?.unwrap_or_default())
+}
+
+fn sha256(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+fn read_snapshot(dir: &Path, revision: u64) -> Result<String, String> {
+    let file = File::open(snapshot_path(dir, revision))
+        .map_err(|e| format!("Revision {} not found: {}", revision, e))?;
+    let mut content = String::new();
+    GzDecoder::new(file)
+        .read_to_string(&mut content)
+        .map_err(|e| format!("Failed to read revision {}: {}", revision, e))?;
+    Ok(content)
+}
+
+// Drops revisions beyond the count or age limit, oldest first. The newest always stays.
+fn prune(revisions: &mut Vec<Revision>, retention: &HistorySettings, now: i64) -> Vec<Revision> {
+    let keep = retention.max_revisions.max(1);
+    let mut removed = Vec::new();
+    if revisions.len() > keep {
+        removed.extend(revisions.drain(..revisions.len() - keep));
+    }
+    if retention.max_age_days > 0 {
+        let cutoff = now - i64::from(retention.max_age_days) * DAY_MILLIS;
+        let expired = revisions[..revisions.len().saturating_sub(1)]
+            .iter()
+            .take_while(|revision| revision.created_at < cutoff)
+            .count();
+        removed.extend(revisions.drain(..expired));
+    }
+    removed
+}
+
+/// Saves `content` as the newest revision of a tab, unless it is what the newest revision
+/// already holds, and drops revisions the history settings no longer keep.
+pub fn record(workspace_id: &str, tab_id: &str, title: &str, content: &str) -> Result<(), String> {
+    let _guard = LOCK.lock().unwrap();
+    let dir = tab_history_dir(workspace_id, tab_id)?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let mut revisions = read_index(&dir)?;
+    let hash = sha256(content);
+    if revisions.last().is_some_and(|newest| newest.sha256 == hash) {
+        return Ok(());
+    }
+
+    let id = revisions.last().map_or(1, |newest| newest.id + 1);
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    let compressed = encoder
+        .write_all(content.as_bytes())
+        .and_then(|_| encoder.finish())
+        .map_err(|e| format!("Failed to compress revision: {}", e))?;
+    persist::write(&snapshot_path(&dir, id), compressed)?;
+
+    let now = chrono::Utc::now().timestamp_millis();
+    revisions.push(Revision {
+        id,
+        created_at: now,
+        title: title.to_string(),
+        size: content.len(),
+        sha256: hash,
+    });
+    let removed = prune(&mut revisions, &settings::load()?.history, now);
+    persist::write_json(&dir.join(INDEX_FILE), &revisions)?;
+
+    for revision in removed {
+        let _ = fs::remove_file(snapshot_path(&dir, revision.id));
+    }
+    Ok(())
+}
+
+/// Every kept revision of a tab, newest first.
+pub fn list(workspace_id: &str, tab_id: &str) -> Result<Vec<Revision>, String> {
+    let mut revisions = read_index(&tab_history_dir(workspace_id, tab_id)?)?;
+    revisions.reverse();
+    Ok(revisions)
+}
+
+/// The content of one revision of a tab.
+pub fn content(workspace_id: &str, tab_id: &str, revision: u64) -> Result<String, String> {
+    read_snapshot(&tab_history_dir(workspace_id, tab_id)?, revision)
+}
+
+/// The line diff that turns revision `from` of a tab into revision `to`.
+pub fn diff(workspace_id: &str, tab_id: &str, from: u64, to: u64) -> Result<Vec<DiffLine>, String> {
+    let dir = tab_history_dir(workspace_id, tab_id)?;
+    let old = read_snapshot(&dir, from)?;
+    let new = read_snapshot(&dir, to)?;
+    Ok(diff_lines(&old, &new))
+}
+
+/// Deletes every revision of a tab.
+pub fn delete(workspace_id: &str, tab_id: &str) -> Result<(), String> {
+    let _guard = LOCK.lock().unwrap();
+    let dir = tab_history_dir(workspace_id, tab_id)?;
+    if dir.exists() {
+        fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// A line diff of `old` and `new` from their longest common subsequence of lines.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut lines = Vec::new();
+    let (mut old_line, mut new_line) = (0, 0);
+    let mut push = |kind: DiffKind, content: &str| {
+        let old_number = (kind != DiffKind::Added).then(|| {
+            old_line += 1;
+            old_line
+        });
+        let new_number = (kind != DiffKind::Removed).then(|| {
+            new_line += 1;
+            new_line
+        });
+        lines.push(DiffLine {
+            kind,
+            old_line: old_number,
+            new_line: new_number,
+            content: content.to_string(),
+        });
+    };
+
+    for line in &old[..prefix] {
+        push(DiffKind::Unchanged, line);
+    }
+    for (kind, line) in diff_middle(old_middle, new_middle) {
+        push(kind, line);
+    }
+    for line in &old[old.len() - suffix..] {
+        push(DiffKind::Unchanged, line);
+    }
+    lines
+}
+
+fn diff_middle<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(DiffKind, &'a str)> {
+    if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+        return old
+            .iter()
+            .map(|line| (DiffKind::Removed, *line))
+            .chain(new.iter().map(|line| (DiffKind::Added, *line)))
+            .collect();
+    }
+
+    // lengths[i][j] is the longest common subsequence of old[i..] and new[j..].
+    let width = new.len() + 1;
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            result.push((DiffKind::Unchanged, old[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            result.push((DiffKind::Removed, old[i]));
+            i += 1;
+        } else {
+            result.push((DiffKind::Added, new[j]));
+            j += 1;
+        }
+    }
+    result.extend(old[i..].iter().map(|line| (DiffKind::Removed, *line)));
+    result.extend(new[j..].iter().map(|line| (DiffKind::Added, *line)));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(id: u64, created_at: i64) -> Revision {
+        Revision {
+            id,
+            created_at,
+            title: "Script".to_string(),
+            size: 0,
+            sha256: String::new(),
+        }
+    }
+
+    fn summary(lines: &[DiffLine]) -> Vec<(DiffKind, Option<usize>, Option<usize>, &str)> {
+        lines
+            .iter()
+            .map(|line| {
+                (
+                    line.kind,
+                    line.old_line,
+                    line.new_line,
+                    line.content.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diff_lines_numbers_each_side() {
+        let lines = diff_lines("a\nb\nc\nd", "a\nx\nc\nd\ne");
+        assert_eq!(
+            summary(&lines),
+            vec![
+                (DiffKind::Unchanged, Some(1), Some(1), "a"),
+                (DiffKind::Removed, Some(2), None, "b"),
+                (DiffKind::Added, None, Some(2), "x"),
+                (DiffKind::Unchanged, Some(3), Some(3), "c"),
+                (DiffKind::Unchanged, Some(4), Some(4), "d"),
+                (DiffKind::Added, None, Some(5), "e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_lines_of_identical_or_empty_text() {
+        assert!(diff_lines("", "").is_empty());
+        assert!(diff_lines("a\nb", "a\nb")
+            .iter()
+            .all(|line| line.kind == DiffKind::Unchanged));
+        assert_eq!(
+            summary(&diff_lines("", "a")),
+            vec![(DiffKind::Added, None, Some(1), "a")]
+        );
+    }
+
+    #[test]
+    fn diff_lines_replaces_oversized_middles() {
+        let old = (0..2_001).map(|i| format!("old {}", i)).collect::<Vec<_>>();
+        let new = (0..2_001).map(|i| format!("new {}", i)).collect::<Vec<_>>();
+        let lines = diff_lines(&old.join("\n"), &new.join("\n"));
+
+        assert_eq!(lines.len(), 4_002);
+        assert!(lines[..2_001]
+            .iter()
+            .all(|line| line.kind == DiffKind::Removed));
+        assert!(lines[2_001..]
+            .iter()
+            .all(|line| line.kind == DiffKind::Added));
+    }
+
+    #[test]
+    fn prune_drops_the_oldest_beyond_the_count() {
+        let mut revisions = (1..=5).map(|id| revision(id, 0)).collect::<Vec<_>>();
+        let retention = HistorySettings {
+            max_revisions: 3,
+            max_age_days: 0,
+        };
+
+        let removed = prune(&mut revisions, &retention, 0);
+        assert_eq!(removed.iter().map(|r| r.id).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(
+            revisions.iter().map(|r| r.id).collect::<Vec<_>>(),
+            [3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn prune_drops_expired_revisions_but_keeps_the_newest() {
+        let now = 10 * DAY_MILLIS;
+        let mut revisions = vec![
+            revision(1, 0),
+            revision(2, DAY_MILLIS),
+            revision(3, 9 * DAY_MILLIS),
+        ];
+        let retention = HistorySettings {
+            max_revisions: 0,
+            max_age_days: 5,
+        };
+
+        let mut kept = revisions.clone();
+        prune(&mut kept, &retention, now);
+        assert_eq!(kept.iter().map(|r| r.id).collect::<Vec<_>>(), [3]);
+
+        let retention = HistorySettings {
+            max_revisions: 10,
+            ..retention
+        };
+        let removed = prune(&mut revisions, &retention, now);
+        assert_eq!(removed.iter().map(|r| r.id).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(revisions.iter().map(|r| r.id).collect::<Vec<_>>(), [3]);
+
+        let mut expired = vec![revision(1, 0)];
+        assert!(prune(&mut expired, &retention, now).is_empty());
+        assert_eq!(expired.len(), 1);
+    }
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::connection::ExecutionResult;
+use crate::{paths, persist, AppState};
+
+const LAST_SCRIPT_FILE: &str = "last_script.txt";
+
+/// A built-in script, either fetched from `url` or run from inline `content`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptConfig {
+    pub fetch: Option<bool>,
+    pub url: Option<String>,
+    pub execute: Option<bool>,
+    #[serde(default)]
+    pub content: Option<String>,
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptsResponse {
+    pub scripts: HashMap<String, ScriptConfig>,
+}
+
+/// Turns a script key such as `infinite_yield` into `Infinite Yield`.
+pub fn format_script_name(key: &str) -> String {
+    key.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => {
+                    let first_upper = first.to_uppercase().collect::<String>();
+                    first_upper + &chars.as_str().to_lowercase()
+                }
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub async fn fetch_script_configs() -> Result<ScriptsResponse, String> {
+    let mut scripts = HashMap::new();
+
+    scripts.insert(
+        "infinite_yield".to_string(),
+        ScriptConfig {
+            fetch: Some(true),
+            url: Some(
+                "https://raw.githubusercontent.com/EdgeIY/infiniteyield/refs/heads/master/source"
+                    .to_string(),
+            ),
+            execute: None,
+            content: None,
+            display_name: Some("Infinite Yield".to_string()),
+        },
+    );
+
+    scripts.insert(
+        "remote_spy".to_string(),
+        ScriptConfig {
+            fetch: None,
+            url: None,
+            execute: Some(true),
+            content: Some(r#"loadstring(game:HttpGet("https://raw.githubusercontent.com/infyiff/backup/main/SimpleSpyV3/main.lua"))()"#.to_string()),
+            display_name: Some("Remote Spy".to_string()),
+        }
+    );
+
+    scripts.insert(
+        "dex_explorer".to_string(),
+        ScriptConfig {
+            fetch: None,
+            url: None,
+            execute: Some(true),
+            content: Some(r#"loadstring(game:HttpGet("https://raw.githubusercontent.com/infyiff/backup/main/dex.lua"))()"#.to_string()),
+            display_name: Some("DEX Explorer".to_string()),
+        }
+    );
+
+    Ok(ScriptsResponse { scripts })
+}
+
+/// Runs the built-in script registered under `key`.
+pub async fn execute_script_by_key(state: &AppState, key: &str) -> Result<ExecutionResult, String> {
+    let configs = fetch_script_configs().await?;
+    let config = configs
+        .scripts
+        .get(key)
+        .ok_or_else(|| format!("{} config not found", format_script_name(key)))?;
+
+    if let Some(true) = config.fetch {
+        let url = config
+            .url
+            .as_ref()
+            .ok_or_else(|| format!("{} URL not found", format_script_name(key)))?;
+        let script = reqwest::get(url)
+            .await
+            .map_err(|e| e.to_string())?
+            .text()
+            .await
+            .map_err(|e| e.to_string())?;
+        state.execute(&script).await.map_err(|e| e.to_string())
+    } else if let Some(true) = config.execute {
+        let content = config
+            .content
+            .as_ref()
+            .ok_or_else(|| format!("{} content not found", format_script_name(key)))?;
+        state.execute(content).await.map_err(|e| e.to_string())
+    } else {
+        Err(format!("Invalid {} config", format_script_name(key)))
+    }
+}
+
+// Kept out of `settings.json` so running a script doesn't broadcast it as a settings change.
+pub(crate) fn last_script_path() -> PathBuf {
+    paths::app_data_dir().join(LAST_SCRIPT_FILE)
+}
+
+pub fn read_last_script() -> Result<String, String> {
+    persist::read_with(&last_script_path(), |path| {
+        fs::read_to_string(path).map_err(|e| e.to_string())
+    })?
+    .ok_or_else(|| "No last script found".to_string())
+}
+
+pub async fn run_last_script(state: &AppState) -> Result<ExecutionResult, String> {
+    let content = read_last_script()?;
+    state.execute(&content).await.map_err(|e| e.to_string())
+}
+
+pub fn save_last_script(script: &str) -> Result<(), String> {
+    persist::write(&last_script_path(), script)
+}
@@ -0,0 +1,315 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tokio::sync::broadcast;
+
+use crate::config;
+use crate::executor_settings::ExecutorSettings;
+use crate::paths;
+use crate::persist;
+use crate::scripts;
+
+const SETTINGS_FILE: &str = "settings.json";
+// The schema version belongs to the migrations, and executor settings are checked and applied
+// to the live connection by `AppState::apply_settings`.
+const PROTECTED_KEYS: &[&str] = &["schema_version", "executor"];
+
+/// Upgrades settings from one schema version to the next. Files the old format kept outside
+/// `settings.json` are pushed to the second argument and deleted once the upgrade is saved.
+type Migration = fn(&mut Value, &mut Vec<PathBuf>) -> Result<(), String>;
+
+/// `MIGRATIONS[n]` upgrades schema version `n` to `n + 1`. Append only.
+const MIGRATIONS: &[Migration] = &[
+    from_legacy_files,
+    from_executor_and_update_files,
+    from_last_script_setting,
+];
+
+/// The schema version [`load`] upgrades every settings file to.
+pub const SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTrayScript {
+    pub id: String,
+    pub name: String,
+    pub content: String,
+    pub order: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrayConfig {
+    pub enabled: bool,
+    pub show_scripts: bool,
+    pub show_last_script: bool,
+    pub custom_scripts: Vec<CustomTrayScript>,
+}
+
+impl Default for TrayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            show_scripts: true,
+            show_last_script: true,
+            custom_scripts: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoExecuteSettings {
+    /// Whether Comet's auto-execute scripts are copied into the executor's folder.
+    pub enabled: bool,
+}
+
+impl Default for AutoExecuteSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceSettings {
+    pub active_workspace: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistorySettings {
+    /// How many revisions of each tab to keep.
+    pub max_revisions: usize,
+    /// Revisions older than this are dropped; 0 keeps them regardless of age.
+    pub max_age_days: u32,
+}
+
+impl Default for HistorySettings {
+    fn default() -> Self {
+        Self {
+            max_revisions: 100,
+            max_age_days: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateSettings {
+    /// The release the user chose not to be told about again.
+    pub skipped_version: Option<String>,
+}
+
+/// Everything Comet remembers between launches, stored in `settings.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub schema_version: u32,
+    /// The editor's settings. The frontend owns their shape; `None` until it first saves them.
+    pub editor: Option<Value>,
+    pub tray: TrayConfig,
+    pub auto_execute: AutoExecuteSettings,
+    pub workspace: WorkspaceSettings,
+    pub history: HistorySettings,
+    pub executor: ExecutorSettings,
+    pub updates: UpdateSettings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            editor: None,
+            tray: TrayConfig::default(),
+            auto_execute: AutoExecuteSettings::default(),
+            workspace: WorkspaceSettings::default(),
+            history: HistorySettings::default(),
+            executor: ExecutorSettings::default(),
+            updates: UpdateSettings::default(),
+        }
+    }
+}
+
+// Serializes read-modify-write cycles so concurrent updates don't drop each other.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn changes() -> &'static broadcast::Sender<Settings> {
+    static CHANGES: OnceLock<broadcast::Sender<Settings>> = OnceLock::new();
+    CHANGES.get_or_init(|| broadcast::channel(16).0)
+}
+
+fn settings_path() -> PathBuf {
+    paths::app_data_dir().join(SETTINGS_FILE)
+}
+
+/// Version 0 is no `settings.json` at all: the tray config, the auto-execute marker file and
+/// the active workspace each lived in their own file. The last script stays in its file.
+fn from_legacy_files(settings: &mut Value, obsolete: &mut Vec<PathBuf>) -> Result<(), String> {
+    let data_dir = paths::app_data_dir();
+
+    let tray_file = data_dir.join("tray_config.json");
+    if let Some(tray) = read_json(&tray_file) {
+        settings["tray"] = tray;
+    }
+    obsolete.push(tray_file);
+
+    let marker = data_dir.join("auto_execute_disabled");
+    if marker.exists() {
+        settings["auto_execute"] = json!({ "enabled": false });
+    }
+    obsolete.push(marker);
+
+    // The workspace list stays in `state.json`; only the active one moves.
+    let workspace_state = data_dir.join("workspaces").join("state.json");
+    if let Some(active) = read_json(&workspace_state).and_then(|state| {
+        state
+            .get("active_workspace")
+            .filter(|active| active.is_string())
+            .cloned()
+    }) {
+        settings["workspace"] = json!({ "active_workspace": active });
+    }
+
+    Ok(())
+}
+
+/// Version 1 kept the executor endpoints in `executor_settings.json` and the skipped update in
+/// `updates.json`, each with a `.bak` copy.
+fn from_executor_and_update_files(
+    settings: &mut Value,
+    obsolete: &mut Vec<PathBuf>,
+) -> Result<(), String> {
+    let data_dir = paths::app_data_dir();
+
+    let executor_file = data_dir.join("executor_settings.json");
+    let executor = persist::read_json::<ExecutorSettings>(&executor_file)
+        .ok()
+        .flatten()
+        .filter(|executor| executor.validate().is_ok());
+    if let Some(executor) = executor {
+        settings["executor"] = serde_json::to_value(executor).map_err(|e| e.to_string())?;
+    }
+
+    let updates_file = data_dir.join("updates.json");
+    if let Some(updates) = persist::read_json::<UpdateSettings>(&updates_file)
+        .ok()
+        .flatten()
+    {
+        settings["updates"] = serde_json::to_value(updates).map_err(|e| e.to_string())?;
+    }
+
+    for file in [executor_file, updates_file] {
+        obsolete.push(persist::backup_path(&file));
+        obsolete.push(file);
+    }
+    Ok(())
+}
+
+/// Version 2 kept the last executed script in `scripts.last_script`, so every execution
+/// rewrote and broadcast the settings. It moves back to its own file.
+fn from_last_script_setting(
+    settings: &mut Value,
+    _obsolete: &mut Vec<PathBuf>,
+) -> Result<(), String> {
+    let Some(scripts) = settings.as_object_mut().and_then(|s| s.remove("scripts")) else {
+        return Ok(());
+    };
+    if let Some(script) = scripts.get("last_script").and_then(Value::as_str) {
+        persist::write(&scripts::last_script_path(), script)?;
+    }
+    Ok(())
+}
+
+// Legacy files that don't parse are dropped rather than blocking the upgrade.
+fn read_json(path: &Path) -> Option<Value> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+fn parse(value: Value) -> Result<Settings, String> {
+    serde_json::from_value(value).map_err(|e| format!("Invalid settings: {}", e))
+}
+
+fn write(settings: &Settings) -> Result<(), String> {
+    persist::write_json(&settings_path(), settings)
+        .map_err(|e| format!("Failed to save settings: {}", e))
+}
+
+// Reads `settings.json`, running and saving any migrations it is behind on.
+fn read() -> Result<Settings, String> {
+    let mut value = persist::read_json(&settings_path())?.unwrap_or_else(|| json!({}));
+    if !value.is_object() {
+        return Err("Invalid settings: expected an object".to_string());
+    }
+
+    let version = value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+    let Some(pending) = MIGRATIONS
+        .get(version..)
+        .filter(|pending| !pending.is_empty())
+    else {
+        return parse(value);
+    };
+
+    let mut obsolete = Vec::new();
+    for migrate in pending {
+        migrate(&mut value, &mut obsolete)?;
+    }
+    value["schema_version"] = json!(SCHEMA_VERSION);
+
+    let settings = parse(value)?;
+    write(&settings)?;
+    for path in obsolete {
+        let _ = fs::remove_file(path);
+    }
+    Ok(settings)
+}
+
+// Saves `settings` if they differ from `previous` and tells every subscriber.
+fn commit(previous: &Settings, settings: Settings) -> Result<Settings, String> {
+    if *previous != settings {
+        write(&settings)?;
+        let _ = changes().send(settings.clone());
+    }
+    Ok(settings)
+}
+
+/// Loads the settings, upgrading older formats to [`SCHEMA_VERSION`] first.
+pub fn load() -> Result<Settings, String> {
+    let _guard = LOCK.lock().unwrap();
+    read()
+}
+
+/// Changes the settings in place and saves them.
+pub fn update(change: impl FnOnce(&mut Settings)) -> Result<Settings, String> {
+    let _guard = LOCK.lock().unwrap();
+    let previous = read()?;
+    let mut settings = previous.clone();
+    change(&mut settings);
+    commit(&previous, settings)
+}
+
+/// Merges a partial settings object into the current settings, key by key, and saves the
+/// result. The whole patch is rejected if any value has the wrong type or it touches the
+/// schema version or the executor settings.
+pub fn apply(patch: Value) -> Result<Settings, String> {
+    if let Some(key) = PROTECTED_KEYS.iter().find(|key| patch.get(**key).is_some()) {
+        return Err(format!("Settings patches can't change '{}'", key));
+    }
+
+    let _guard = LOCK.lock().unwrap();
+    let previous = read()?;
+    let mut value = serde_json::to_value(&previous).map_err(|e| e.to_string())?;
+    config::merge(&mut value, patch);
+    commit(&previous, parse(value)?)
+}
+
+/// Receives the settings after every change, whichever window or module made it.
+pub fn subscribe() -> broadcast::Receiver<Settings> {
+    changes().subscribe()
+}
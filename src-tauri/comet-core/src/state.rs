@@ -0,0 +1,374 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{broadcast, Notify, RwLock};
+
+use crate::backend::{EndpointConfig, ExecuteError};
+use crate::config;
+use crate::connection::{ConnectionManager, ExecutionResult, ExecutionTarget, InstanceResult};
+use crate::detector;
+use crate::discovery::DiscoveryOptions;
+use crate::executor_settings::ExecutorSettings;
+use crate::process::{ProcessEvent, RobloxProcess};
+use crate::settings::{self, Settings};
+use crate::supervisor::ConnectionMetrics;
+use crate::ApiType;
+
+const STATUS_CAPACITY: usize = 32;
+
+/// Snapshot of the executor connection as shown in the UI.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionStatus {
+    pub is_connected: bool,
+    pub port: Option<u16>,
+    pub current_port: u16,
+    pub is_connecting: bool,
+    pub api_type: ApiType,
+    pub metrics: ConnectionMetrics,
+    /// Running Roblox players, so "Roblox is closed" can be told apart from "not injected".
+    pub roblox_processes: Vec<RobloxProcess>,
+}
+
+impl ConnectionStatus {
+    fn roblox_pids(&self) -> Vec<u32> {
+        self.roblox_processes
+            .iter()
+            .map(|process| process.pid)
+            .collect()
+    }
+}
+
+/// Shared connection state. Cheap to clone; every clone sees the same connection.
+#[derive(Clone)]
+pub struct AppState {
+    pub connection: Arc<ConnectionManager>,
+    pub(crate) status: Arc<RwLock<ConnectionStatus>>,
+    status_updates: broadcast::Sender<ConnectionStatus>,
+    process_events: broadcast::Sender<ProcessEvent>,
+    pub(crate) wake: Arc<Notify>,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppState {
+    /// Detects the installed executor and loads the saved executor endpoints and discovery
+    /// timeouts.
+    pub fn new() -> Self {
+        let detected_api = detector::detect_executor();
+        let settings = ExecutorSettings::load();
+        Self::from_connection(
+            detected_api,
+            ConnectionManager::new(detected_api, settings.endpoints())
+                .with_discovery_options(settings.discovery),
+        )
+    }
+
+    /// Creates state for `api_type` using explicit endpoints instead of the saved settings.
+    pub fn with_endpoints(api_type: ApiType, endpoints: HashMap<ApiType, EndpointConfig>) -> Self {
+        Self::from_connection(api_type, ConnectionManager::new(api_type, endpoints))
+    }
+
+    fn from_connection(api_type: ApiType, connection: ConnectionManager) -> Self {
+        let (status_updates, _) = broadcast::channel(STATUS_CAPACITY);
+        let (process_events, _) = broadcast::channel(STATUS_CAPACITY);
+        let (min_port, _max_port) = connection.endpoint(api_type).port_range();
+
+        Self {
+            connection: Arc::new(connection),
+            status: Arc::new(RwLock::new(ConnectionStatus {
+                is_connected: false,
+                port: None,
+                current_port: min_port,
+                is_connecting: false,
+                api_type,
+                metrics: ConnectionMetrics::default(),
+                roblox_processes: Vec::new(),
+            })),
+            status_updates,
+            process_events,
+            wake: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Receives a status every time the connection state changes.
+    pub fn subscribe_status(&self) -> broadcast::Receiver<ConnectionStatus> {
+        self.status_updates.subscribe()
+    }
+
+    /// Receives an event every time a Roblox player launches or exits.
+    pub fn subscribe_processes(&self) -> broadcast::Receiver<ProcessEvent> {
+        self.process_events.subscribe()
+    }
+
+    pub async fn status(&self) -> ConnectionStatus {
+        let mut status = self.status.read().await.clone();
+        status.metrics.refresh_uptime();
+        status
+    }
+
+    // Applies a change to the status and broadcasts it if the connection state moved.
+    pub(crate) async fn modify_status(
+        &self,
+        change: impl FnOnce(&mut ConnectionStatus),
+    ) -> ConnectionStatus {
+        let mut status = self.status.write().await;
+        let was_connected = status.is_connected;
+        let before = (
+            status.is_connected,
+            status.is_connecting,
+            status.port,
+            status.current_port,
+            status.api_type,
+            status.roblox_pids(),
+        );
+
+        change(&mut status);
+
+        if status.is_connected && !was_connected {
+            status.metrics.on_connected();
+        } else if !status.is_connected && was_connected {
+            status.metrics.on_disconnected();
+        }
+        status.metrics.refresh_uptime();
+
+        let after = (
+            status.is_connected,
+            status.is_connecting,
+            status.port,
+            status.current_port,
+            status.api_type,
+            status.roblox_pids(),
+        );
+        if before != after {
+            let _ = self.status_updates.send(status.clone());
+        }
+
+        status.clone()
+    }
+
+    async fn update_status(&self, is_connected: bool, port: Option<u16>) {
+        self.modify_status(|status| {
+            status.is_connected = is_connected;
+            status.port = port;
+            status.current_port = port.unwrap_or(status.current_port);
+        })
+        .await;
+    }
+
+    /// Records the running Roblox players, announcing launches and exits. A launch wakes the
+    /// supervisor so the executor is picked up as soon as it injects.
+    pub async fn set_roblox_processes(&self, processes: Vec<RobloxProcess>) {
+        let previous = self.status.read().await.roblox_processes.clone();
+
+        let mut launched = false;
+        let mut current = Vec::with_capacity(processes.len());
+        for mut process in processes {
+            match previous.iter().find(|known| known.pid == process.pid) {
+                // Keep the first start time seen; ps only reports it to the second.
+                Some(known) => process.started_at = known.started_at,
+                None => {
+                    launched = true;
+                    let _ = self.process_events.send(ProcessEvent::Launched {
+                        process: process.clone(),
+                    });
+                }
+            }
+            current.push(process);
+        }
+        for known in &previous {
+            if !current.iter().any(|process| process.pid == known.pid) {
+                let _ = self
+                    .process_events
+                    .send(ProcessEvent::Exited { pid: known.pid });
+            }
+        }
+
+        self.modify_status(|status| status.roblox_processes = current)
+            .await;
+        if launched {
+            self.wake.notify_one();
+        }
+    }
+
+    /// Runs a script on the connected executor, falling back to any discovered instance.
+    pub async fn execute(&self, script: &str) -> Result<ExecutionResult, ExecuteError> {
+        match self.connection.execute(script).await {
+            Ok(result) => {
+                self.update_status(true, Some(result.port)).await;
+                Ok(result)
+            }
+            Err(e) => {
+                self.update_status(false, None).await;
+                self.wake.notify_one();
+                Err(e)
+            }
+        }
+    }
+
+    /// Runs a script on several executor instances at once.
+    pub async fn send_to(&self, script: &str, target: ExecutionTarget) -> Vec<InstanceResult> {
+        let results = self.connection.send_to(script, target).await;
+
+        if let Some(result) = results.iter().find(|result| result.success) {
+            self.update_status(true, Some(result.port)).await;
+        }
+
+        results
+    }
+
+    /// Sends an executor setting as a `key value` script on the current connection.
+    pub async fn change_setting(&self, key: &str, value: &str) -> bool {
+        let content = format!("{} {}", key, value);
+        match self.connection.send(&content).await {
+            Ok(result) => result.success,
+            Err(_) => {
+                self.update_status(false, None).await;
+                self.wake.notify_one();
+                false
+            }
+        }
+    }
+
+    pub async fn set_api_type(&self, api_type: ApiType) -> ConnectionStatus {
+        self.connection.set_api_type(api_type).await;
+
+        let (min_port, _max_port) = self.connection.endpoint(api_type).port_range();
+
+        let status = self
+            .modify_status(|status| {
+                status.api_type = api_type;
+                status.is_connected = false;
+                status.port = None;
+                status.current_port = min_port;
+            })
+            .await;
+        self.wake.notify_one();
+
+        status
+    }
+
+    /// Retries the handshake on the selected port.
+    pub async fn refresh_connection(&self) -> ConnectionStatus {
+        let current_port = self.status.read().await.current_port;
+
+        if self.connection.connect(current_port).await {
+            self.update_status(true, Some(current_port)).await;
+        } else {
+            self.update_status(false, None).await;
+            self.wake.notify_one();
+        }
+
+        self.status().await
+    }
+
+    /// Moves the selected port to the next one in range, wrapping around.
+    pub async fn increment_port(&self) -> ConnectionStatus {
+        let (current_port, api_type) = {
+            let status = self.status.read().await;
+            (status.current_port, status.api_type)
+        };
+
+        let (min_port, max_port) = self.connection.endpoint(api_type).port_range();
+        let next_port = if current_port >= max_port {
+            min_port
+        } else {
+            current_port + 1
+        };
+
+        self.set_current_port(next_port).await;
+        self.status().await
+    }
+
+    async fn set_current_port(&self, port: u16) {
+        self.connection.disconnect().await;
+        self.modify_status(|status| {
+            status.is_connected = false;
+            status.port = None;
+            status.current_port = port;
+        })
+        .await;
+        self.wake.notify_one();
+    }
+
+    /// Validates, saves and applies new discovery timeouts.
+    pub async fn set_discovery_options(&self, options: DiscoveryOptions) -> Result<(), String> {
+        options.validate()?;
+        ExecutorSettings {
+            discovery: options,
+            ..ExecutorSettings::load()
+        }
+        .save()?;
+        self.connection.set_discovery_options(options).await;
+        Ok(())
+    }
+
+    /// The host, port range and handshake in use for every executor.
+    pub fn endpoints(&self) -> HashMap<ApiType, EndpointConfig> {
+        ApiType::ALL
+            .into_iter()
+            .map(|api_type| (api_type, self.connection.endpoint(api_type)))
+            .collect()
+    }
+
+    /// Merges a settings patch like [`settings::apply`], except that its `executor` section is
+    /// validated and applied to the connection through [`Self::update_executor_settings`].
+    /// Nothing is saved if either part is invalid.
+    pub async fn apply_settings(&self, mut patch: Value) -> Result<Settings, String> {
+        let executor = match patch
+            .as_object_mut()
+            .and_then(|patch| patch.remove("executor"))
+        {
+            Some(executor_patch) => {
+                let mut merged =
+                    serde_json::to_value(settings::load()?.executor).map_err(|e| e.to_string())?;
+                config::merge(&mut merged, executor_patch);
+                let executor: ExecutorSettings = serde_json::from_value(merged)
+                    .map_err(|e| format!("Invalid executor settings: {}", e))?;
+                executor.validate()?;
+                Some(executor)
+            }
+            None => None,
+        };
+
+        let mut applied = settings::apply(patch)?;
+        if let Some(executor) = executor {
+            self.update_executor_settings(executor.clone()).await?;
+            applied.executor = executor;
+        }
+        Ok(applied)
+    }
+
+    /// Validates, saves and applies new executor settings, dropping the current connection.
+    pub async fn update_executor_settings(
+        &self,
+        settings: ExecutorSettings,
+    ) -> Result<HashMap<ApiType, EndpointConfig>, String> {
+        settings.validate()?;
+        settings.save()?;
+
+        let endpoints = settings.endpoints();
+        self.connection.set_endpoints(endpoints.clone()).await;
+        self.connection
+            .set_discovery_options(settings.discovery)
+            .await;
+
+        let api_type = self.connection.api_type().await;
+        let (min_port, max_port) = endpoints[&api_type].port_range();
+        self.modify_status(|status| {
+            status.is_connected = false;
+            status.port = None;
+            if !(min_port..=max_port).contains(&status.current_port) {
+                status.current_port = min_port;
+            }
+        })
+        .await;
+        self.wake.notify_one();
+
+        Ok(endpoints)
+    }
+}
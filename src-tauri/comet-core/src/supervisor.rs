@@ -0,0 +1,199 @@
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+use crate::AppState;
+
+const CHECK_INTERVAL: Duration = Duration::from_millis(2500);
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConnectionMetrics {
+    pub latency_ms: Option<u64>,
+    pub connected_since: Option<i64>,
+    pub uptime_ms: u64,
+    pub reconnect_count: u32,
+    pub failed_attempts: u32,
+    pub next_retry_ms: Option<u64>,
+    #[serde(skip)]
+    has_connected: bool,
+}
+
+impl ConnectionMetrics {
+    pub fn on_connected(&mut self) {
+        if self.has_connected {
+            self.reconnect_count += 1;
+        }
+        self.has_connected = true;
+        self.connected_since = Some(chrono::Utc::now().timestamp_millis());
+        self.failed_attempts = 0;
+        self.next_retry_ms = None;
+    }
+
+    pub fn on_disconnected(&mut self) {
+        self.connected_since = None;
+        self.uptime_ms = 0;
+        self.latency_ms = None;
+    }
+
+    pub fn refresh_uptime(&mut self) {
+        if let Some(since) = self.connected_since {
+            let now = chrono::Utc::now().timestamp_millis();
+            self.uptime_ms = now.saturating_sub(since).max(0) as u64;
+        }
+    }
+}
+
+struct Backoff {
+    next: Duration,
+}
+
+impl Backoff {
+    fn new() -> Self {
+        Self {
+            next: INITIAL_BACKOFF,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.next = INITIAL_BACKOFF;
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(MAX_BACKOFF);
+        delay
+    }
+}
+
+// Connects to `port`, returning how long the handshake took if it succeeded.
+async fn timed_connect(state: &AppState, port: u16) -> Option<u64> {
+    let started = Instant::now();
+    state
+        .connection
+        .connect(port)
+        .await
+        .then(|| started.elapsed().as_millis() as u64)
+}
+
+/// Checks the connection once, reconnecting through discovery if it dropped, and records the
+/// outcome in the status. Latency is the time of the handshake that succeeded.
+pub async fn check_connection(state: &AppState) -> bool {
+    let started = Instant::now();
+    if state.connection.is_connected().await {
+        let port = state.connection.port().await;
+        state
+            .modify_status(|status| {
+                status.is_connected = true;
+                status.port = port;
+                status.metrics.latency_ms = Some(started.elapsed().as_millis() as u64);
+            })
+            .await;
+        return true;
+    }
+
+    let current_port = state
+        .modify_status(|status| {
+            status.is_connected = false;
+            status.port = None;
+            status.is_connecting = true;
+        })
+        .await
+        .current_port;
+
+    let mut connected = timed_connect(state, current_port)
+        .await
+        .map(|latency| (current_port, latency));
+    if connected.is_none() {
+        for candidate in state.connection.discover().await {
+            if let Some(latency) = timed_connect(state, candidate).await {
+                connected = Some((candidate, latency));
+                break;
+            }
+        }
+    }
+
+    state
+        .modify_status(|status| {
+            status.is_connecting = false;
+            match connected {
+                Some((port, latency)) => {
+                    status.is_connected = true;
+                    status.port = Some(port);
+                    status.current_port = port;
+                    status.metrics.latency_ms = Some(latency);
+                }
+                None => status.metrics.failed_attempts += 1,
+            }
+        })
+        .await;
+
+    connected.is_some()
+}
+
+// Polls at a steady pace while connected, backs off exponentially while nothing answers, and
+// wakes up early whenever a send fails or the user changes executor or port.
+pub async fn run(state: AppState) {
+    let mut backoff = Backoff::new();
+
+    loop {
+        let delay = if check_connection(&state).await {
+            backoff.reset();
+            CHECK_INTERVAL
+        } else {
+            let delay = backoff.next_delay();
+            state
+                .modify_status(|status| {
+                    status.metrics.next_retry_ms = Some(delay.as_millis() as u64)
+                })
+                .await;
+            delay
+        };
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = state.wake.notified() => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let mut backoff = Backoff::new();
+        let delays = (0..8)
+            .map(|_| backoff.next_delay().as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, [500, 1000, 2000, 4000, 8000, 16000, 30000, 30000]);
+    }
+
+    #[test]
+    fn backoff_starts_over_after_a_reset() {
+        let mut backoff = Backoff::new();
+        backoff.next_delay();
+        backoff.next_delay();
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), INITIAL_BACKOFF);
+    }
+
+    #[test]
+    fn metrics_count_reconnects_and_clear_failures() {
+        let mut metrics = ConnectionMetrics {
+            failed_attempts: 3,
+            next_retry_ms: Some(2000),
+            ..Default::default()
+        };
+        metrics.on_connected();
+        assert_eq!((metrics.reconnect_count, metrics.failed_attempts), (0, 0));
+        assert!(metrics.connected_since.is_some() && metrics.next_retry_ms.is_none());
+
+        metrics.latency_ms = Some(4);
+        metrics.on_disconnected();
+        assert!(metrics.connected_since.is_none() && metrics.latency_ms.is_none());
+        metrics.on_connected();
+        assert_eq!(metrics.reconnect_count, 1);
+    }
+}
@@ -0,0 +1,373 @@
+use crate::persist;
+use crate::revisions;
+use crate::workspace::get_workspace_tabs_dir;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const INFO_EXTENSION: &str = ".meta.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tab {
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    pub language: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabMetadata {
+    pub id: String,
+    pub title: String,
+}
+
+/// What `<id>.meta.json` holds next to a tab's content. Times are Unix milliseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabInfo {
+    pub id: String,
+    pub title: String,
+    pub language: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabState {
+    pub active_tab: Option<String>,
+    pub tab_order: Vec<String>,
+    pub tab_metadata: Vec<TabMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub tab_id: String,
+    pub title: String,
+    pub line_number: usize,
+    pub line_content: String,
+    pub column_start: usize,
+    pub column_end: usize,
+}
+
+fn get_state_file(workspace_id: &str) -> PathBuf {
+    let mut path = get_workspace_tabs_dir(workspace_id);
+    path.push("state.json");
+    path
+}
+
+fn read_file_content(path: &Path) -> Result<String, String> {
+    let mut file = File::open(path).map_err(|e| e.to_string())?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|e| e.to_string())?;
+    Ok(content)
+}
+
+fn get_tab_id_from_title(title: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(title.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+// Ids name files, so only the characters nanoid and hex digests use are allowed.
+pub(crate) fn validate_tab_id(tab_id: &str) -> Result<(), String> {
+    if !tab_id.is_empty()
+        && tab_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        Ok(())
+    } else {
+        Err(format!("Invalid tab id '{}'", tab_id))
+    }
+}
+
+fn content_path(tabs_dir: &Path, tab_id: &str) -> PathBuf {
+    tabs_dir.join(format!("{}.lua", tab_id))
+}
+
+fn info_path(tabs_dir: &Path, tab_id: &str) -> PathBuf {
+    tabs_dir.join(format!("{}{}", tab_id, INFO_EXTENSION))
+}
+
+fn read_info(tabs_dir: &Path, tab_id: &str) -> Option<TabInfo> {
+    persist::read_json(&info_path(tabs_dir, tab_id))
+        .ok()
+        .flatten()
+}
+
+fn write_info(tabs_dir: &Path, info: &TabInfo) -> Result<(), String> {
+    persist::write_json(&info_path(tabs_dir, &info.id), info)
+}
+
+fn modified_millis(path: &Path) -> i64 {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map(|modified| chrono::DateTime::<chrono::Utc>::from(modified).timestamp_millis())
+        .unwrap_or_else(|_| chrono::Utc::now().timestamp_millis())
+}
+
+/// Moves tabs saved before ids were used, as `<title>.lua` without an info file, to
+/// `<id>.lua`. The id comes from the saved tab state when it knows the title.
+fn migrate_title_files(workspace_id: &str, tabs_dir: &Path, tab_state: Option<&TabState>) {
+    let Ok(entries) = fs::read_dir(tabs_dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(stem) = path
+            .file_stem()
+            .filter(|_| path.is_file() && path.extension().is_some_and(|ext| ext == "lua"))
+            .map(|stem| stem.to_string_lossy().into_owned())
+        else {
+            continue;
+        };
+        if info_path(tabs_dir, &stem).exists() {
+            continue;
+        }
+
+        let title = format!("{}.lua", stem);
+        let hashed_id = get_tab_id_from_title(&format!("{}_{}", workspace_id, title));
+        let id = tab_state
+            .and_then(|state| {
+                // Titles without `.lua` were saved with it appended.
+                state.tab_metadata.iter().find(|meta| {
+                    meta.title == title || format!("{}.lua", meta.title.trim()) == title
+                })
+            })
+            .map(|meta| meta.id.clone())
+            .filter(|id| validate_tab_id(id).is_ok() && !content_path(tabs_dir, id).exists())
+            .unwrap_or(hashed_id);
+        if content_path(tabs_dir, &id).exists() {
+            continue;
+        }
+
+        let modified = modified_millis(&path);
+        let info = TabInfo {
+            id: id.clone(),
+            title,
+            language: "lua".to_string(),
+            created_at: modified,
+            updated_at: modified,
+        };
+        // The info file goes first: until the rename, the title file is migrated again.
+        if let Err(e) = write_info(tabs_dir, &info)
+            .and_then(|_| fs::rename(&path, content_path(tabs_dir, &id)).map_err(|e| e.to_string()))
+        {
+            eprintln!("Failed to migrate tab {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Writes a tab to `<id>.lua` and its title, language and timestamps to `<id>.meta.json`, and
+/// records the content as a revision. Saving a tab that hasn't changed does nothing.
+pub fn save_tab(workspace_id: &str, tab: &Tab) -> Result<(), String> {
+    validate_tab_id(&tab.id)?;
+    let tabs_dir = get_workspace_tabs_dir(workspace_id);
+    let file_path = content_path(&tabs_dir, &tab.id);
+    let existing = read_info(&tabs_dir, &tab.id);
+
+    if let Some(info) = &existing {
+        let unchanged = info.title == tab.title
+            && info.language == tab.language
+            && read_file_content(&file_path).is_ok_and(|content| content == tab.content);
+        if unchanged {
+            return Ok(());
+        }
+    }
+
+    // The info file goes first: `<id>.lua` without one would be migrated as a title file.
+    let now = chrono::Utc::now().timestamp_millis();
+    write_info(
+        &tabs_dir,
+        &TabInfo {
+            id: tab.id.clone(),
+            title: tab.title.clone(),
+            language: tab.language.clone(),
+            created_at: existing.map_or(now, |info| info.created_at),
+            updated_at: now,
+        },
+    )?;
+    persist::write(&file_path, &tab.content)?;
+
+    // The tab itself is saved by now; a failed snapshot only costs history.
+    if let Err(e) = revisions::record(workspace_id, &tab.id, &tab.title, &tab.content) {
+        eprintln!("Failed to record a revision of {}: {}", tab.id, e);
+    }
+    Ok(())
+}
+
+pub fn delete_tab(workspace_id: &str, tab_id: &str) -> Result<(), String> {
+    validate_tab_id(tab_id)?;
+    let tabs_dir = get_workspace_tabs_dir(workspace_id);
+
+    persist::remove(&content_path(&tabs_dir, tab_id))?;
+    persist::remove(&info_path(&tabs_dir, tab_id))?;
+    revisions::delete(workspace_id, tab_id)
+}
+
+/// Puts the content of `revision` back into a tab, which records it as the newest revision,
+/// and returns the restored tab.
+pub fn restore_revision(workspace_id: &str, tab_id: &str, revision: u64) -> Result<Tab, String> {
+    validate_tab_id(tab_id)?;
+    let tabs_dir = get_workspace_tabs_dir(workspace_id);
+    let info = read_info(&tabs_dir, tab_id).ok_or_else(|| "Tab does not exist".to_string())?;
+
+    let tab = Tab {
+        id: info.id,
+        title: info.title,
+        content: revisions::content(workspace_id, tab_id, revision)?,
+        language: info.language,
+    };
+    save_tab(workspace_id, &tab)?;
+    Ok(tab)
+}
+
+/// Persists the active tab, tab order and the id/title mapping for a workspace.
+pub fn save_tab_state(
+    workspace_id: &str,
+    active_tab: Option<String>,
+    tab_order: Vec<String>,
+    tabs: Vec<Tab>,
+) -> Result<(), String> {
+    let state = TabState {
+        active_tab: active_tab.clone(),
+        tab_order: tab_order.clone(),
+        tab_metadata: tabs
+            .into_iter()
+            .map(|tab| TabMetadata {
+                id: tab.id,
+                title: tab.title,
+            })
+            .collect(),
+    };
+
+    let state_file = get_state_file(workspace_id);
+    persist::write_json(&state_file, &state)
+}
+
+/// Loads every tab in a workspace in saved order, or a single untitled tab if there are none.
+pub fn load_tabs(workspace_id: &str) -> Result<Vec<Tab>, String> {
+    let tabs_dir = get_workspace_tabs_dir(workspace_id);
+    let state_file = get_state_file(workspace_id);
+
+    let mut tabs = Vec::new();
+    let tab_state: Option<TabState> = persist::read_json(&state_file).unwrap_or_else(|e| {
+        eprintln!("Ignoring tab state: {}", e);
+        None
+    });
+
+    migrate_title_files(workspace_id, &tabs_dir, tab_state.as_ref());
+
+    if let Ok(entries) = fs::read_dir(&tabs_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(info) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(INFO_EXTENSION))
+                .and_then(|id| read_info(&tabs_dir, id))
+            else {
+                continue;
+            };
+
+            let content = persist::read_with(&content_path(&tabs_dir, &info.id), read_file_content);
+            if let Ok(Some(content)) = content {
+                tabs.push(Tab {
+                    id: info.id,
+                    title: info.title,
+                    content,
+                    language: info.language,
+                });
+            }
+        }
+    }
+
+    if let Some(state) = tab_state {
+        tabs.sort_by_key(|tab| {
+            state
+                .tab_order
+                .iter()
+                .position(|id| id == &tab.id)
+                .unwrap_or(usize::MAX)
+        });
+    }
+
+    if tabs.is_empty() {
+        let default_title = "untitled.lua";
+        let id = get_tab_id_from_title(&format!("{}_{}", workspace_id, default_title));
+        tabs.push(Tab {
+            id,
+            title: default_title.to_string(),
+            content: "-- New File\n".to_string(),
+            language: "lua".to_string(),
+        });
+    }
+
+    Ok(tabs)
+}
+
+pub fn get_tab_state(workspace_id: &str) -> Result<TabState, String> {
+    let state_file = get_state_file(workspace_id);
+
+    if let Some(state) = persist::read_json(&state_file)? {
+        Ok(state)
+    } else {
+        let default_title = "untitled.lua";
+        let default_id = get_tab_id_from_title(&format!("{}_{}", workspace_id, default_title));
+        Ok(TabState {
+            active_tab: Some(default_id.clone()),
+            tab_order: vec![default_id],
+            tab_metadata: vec![TabMetadata {
+                id: get_tab_id_from_title(&format!("{}_{}", workspace_id, default_title)),
+                title: default_title.to_string(),
+            }],
+        })
+    }
+}
+
+pub fn rename_tab(workspace_id: &str, tab_id: &str, new_title: &str) -> Result<(), String> {
+    validate_tab_id(tab_id)?;
+    let tabs_dir = get_workspace_tabs_dir(workspace_id);
+    let mut info = read_info(&tabs_dir, tab_id).ok_or_else(|| "Tab does not exist".to_string())?;
+
+    info.title = new_title.to_string();
+    info.updated_at = chrono::Utc::now().timestamp_millis();
+    write_info(&tabs_dir, &info)
+}
+
+pub fn export_tab(content: &str, target_path: &Path) -> Result<(), String> {
+    fs::write(target_path, content).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Case-insensitive search across every tab in a workspace, one result per matching line.
+pub fn search_tabs(workspace_id: &str, query: &str) -> Result<Vec<SearchResult>, String> {
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let tabs = load_tabs(workspace_id)?;
+    let mut results = Vec::new();
+    let query = query.to_lowercase();
+
+    for tab in tabs {
+        for (line_idx, line) in tab.content.lines().enumerate() {
+            let line_lower = line.to_lowercase();
+            if let Some(column_start) = line_lower.find(&query) {
+                results.push(SearchResult {
+                    tab_id: tab.id.clone(),
+                    title: tab.title.clone(),
+                    line_number: line_idx + 1,
+                    line_content: line.to_string(),
+                    column_start,
+                    column_end: column_start + query.len(),
+                });
+            }
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,268 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::brand::Brand;
+use crate::{auto_execute, paths, ApiType};
+
+const BACKUP_DIR: &str = "Documents/Comet Backups";
+
+/// Something the uninstaller can remove, as shown in the preview.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UninstallTarget {
+    pub label: String,
+    pub path: String,
+    pub exists: bool,
+    /// Total size of the file or folder, without following symlinks.
+    pub size_bytes: u64,
+    /// Lives in `/Applications` and is removed with administrator privileges.
+    pub requires_admin: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemovalStatus {
+    Removed,
+    NotFound,
+    Failed,
+}
+
+/// What happened to one selected path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RemovalResult {
+    pub path: String,
+    pub status: RemovalStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn size_of(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| size_of(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+fn locations(brand: &Brand) -> Result<Vec<(String, PathBuf)>, String> {
+    let executor = brand.executor_name();
+    let applications = paths::applications_dir();
+    let library = paths::home_dir()?.join("Library");
+
+    Ok(vec![
+        (format!("{} data", executor), brand.data_dir()?),
+        (format!("{} settings", executor), brand.settings_dir()?),
+        ("Roblox".to_string(), applications.join("Roblox.app")),
+        (
+            "Roblox data".to_string(),
+            library.join("Application Support/Roblox"),
+        ),
+        (
+            "Roblox Studio cache".to_string(),
+            library.join("Caches/com.roblox.RobloxStudio"),
+        ),
+        (
+            "Roblox cache".to_string(),
+            library.join("Caches/com.roblox.Roblox"),
+        ),
+        ("Roblox logs".to_string(), library.join("Logs/Roblox")),
+        (
+            "Roblox preferences".to_string(),
+            library.join("Preferences/com.roblox.Roblox.plist"),
+        ),
+        (
+            "Roblox Studio preferences".to_string(),
+            library.join("Preferences/com.roblox.RobloxStudio.plist"),
+        ),
+        (executor.to_string(), brand.app_path()),
+    ])
+}
+
+/// Lists everything an uninstall of the brand's executor and Roblox would remove. Nothing is
+/// touched.
+pub fn preview(brand: &Brand) -> Result<Vec<UninstallTarget>, String> {
+    let applications = paths::applications_dir();
+    Ok(locations(brand)?
+        .into_iter()
+        .map(|(label, path)| UninstallTarget {
+            exists: fs::symlink_metadata(&path).is_ok(),
+            size_bytes: size_of(&path),
+            requires_admin: path.starts_with(&applications),
+            path: path.to_string_lossy().into_owned(),
+            label,
+        })
+        .collect())
+}
+
+// Entry names inside the archive next to the folders they are read from.
+fn backup_sources(brand: &Brand) -> Result<Vec<(String, PathBuf)>, String> {
+    let app_data = paths::app_data_dir();
+
+    let mut sources = vec![
+        ("comet/scripts".to_string(), app_data.join("scripts")),
+        ("comet/workspaces".to_string(), app_data.join("workspaces")),
+        (
+            format!("{}/workspace", brand.executor_name()),
+            brand.data_dir()?.join("workspace"),
+        ),
+    ];
+    for api_type in ApiType::ALL {
+        sources.push((
+            format!("{}/autoexecute", api_type.backend().name()),
+            auto_execute::get_auto_execute_dir(api_type)?,
+        ));
+    }
+    Ok(sources)
+}
+
+/// Where backups are written, outside of anything the uninstaller removes.
+pub fn backup_dir() -> Result<PathBuf, String> {
+    Ok(paths::home_dir()?.join(BACKUP_DIR))
+}
+
+/// Archives Comet's scripts and workspaces plus every executor's auto-execute and workspace
+/// folder into a `.tar.gz` in [`backup_dir`] and returns its path.
+pub fn backup(brand: &Brand) -> Result<PathBuf, String> {
+    let sources = backup_sources(brand)?
+        .into_iter()
+        .filter(|(_, path)| path.is_dir())
+        .collect::<Vec<_>>();
+    if sources.is_empty() {
+        return Err("There are no scripts or workspaces to back up".to_string());
+    }
+
+    let dir = backup_dir()?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let path = dir.join(format!(
+        "comet-backup-{}.tar.gz",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let file = File::create(&path).map_err(|e| format!("Failed to create backup: {}", e))?;
+    let mut archive = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    archive.follow_symlinks(false);
+
+    let written = sources
+        .iter()
+        .try_for_each(|(name, source)| archive.append_dir_all(name, source))
+        .and_then(|_| archive.into_inner())
+        .and_then(|encoder| encoder.finish());
+    if let Err(e) = written {
+        let _ = fs::remove_file(&path);
+        return Err(format!("Failed to write backup: {}", e));
+    }
+
+    Ok(path)
+}
+
+fn remove_path(path: &Path) -> std::io::Result<()> {
+    if fs::symlink_metadata(path)?.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Removes the selected paths, which must come from [`preview`]. Paths in `/Applications` are
+/// handed to `remove_privileged` together so the user is asked for their password once.
+pub fn remove(
+    brand: &Brand,
+    selected: &[String],
+    remove_privileged: impl FnOnce(&[PathBuf]) -> Result<(), String>,
+) -> Result<Vec<RemovalResult>, String> {
+    let applications = paths::applications_dir();
+    let known = locations(brand)?;
+    let mut targets: Vec<PathBuf> = Vec::new();
+    for selected in selected {
+        let (_, path) = known
+            .iter()
+            .find(|(_, path)| path.to_string_lossy() == selected.as_str())
+            .ok_or_else(|| format!("{} is not part of the uninstall", selected))?;
+        if !targets.contains(path) {
+            targets.push(path.clone());
+        }
+    }
+
+    let privileged = targets
+        .iter()
+        .filter(|path| path.starts_with(&applications) && path.exists())
+        .cloned()
+        .collect::<Vec<_>>();
+    let privileged_error = if privileged.is_empty() {
+        None
+    } else {
+        remove_privileged(&privileged).err()
+    };
+
+    Ok(targets
+        .into_iter()
+        .map(|path| {
+            let result = if privileged.contains(&path) {
+                match &privileged_error {
+                    Some(e) => Err(e.clone()),
+                    None if path.exists() => Err("Still exists after removal".to_string()),
+                    None => Ok(RemovalStatus::Removed),
+                }
+            } else if fs::symlink_metadata(&path).is_err() {
+                Ok(RemovalStatus::NotFound)
+            } else {
+                remove_path(&path)
+                    .map(|_| RemovalStatus::Removed)
+                    .map_err(|e| e.to_string())
+            };
+            let path = path.to_string_lossy().into_owned();
+
+            match result {
+                Ok(status) => RemovalResult {
+                    path,
+                    status,
+                    error: None,
+                },
+                Err(e) => RemovalResult {
+                    path,
+                    status: RemovalStatus::Failed,
+                    error: Some(e),
+                },
+            }
+        })
+        .collect())
+}
+
+// Quoted for the shell, then escaped for the AppleScript string around it.
+pub(crate) fn quote(path: &Path) -> String {
+    let shell = format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"));
+    shell.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Deletes `paths` in one `rm -rf` run with administrator privileges via `osascript`.
+pub fn remove_with_admin(paths: &[PathBuf]) -> Result<(), String> {
+    let quoted = paths.iter().map(|path| quote(path)).collect::<Vec<_>>();
+    let command = format!(
+        "do shell script \"rm -rf {}\" with administrator privileges",
+        quoted.join(" ")
+    );
+    let output = Command::new("osascript")
+        .args(["-e", &command])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Administrator removal failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
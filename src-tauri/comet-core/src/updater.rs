@@ -0,0 +1,160 @@
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::installer::{self, InstallProgress};
+use crate::paths;
+use crate::settings;
+
+/// A published Comet release, as described by the brand's update manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseManifest {
+    pub version: String,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub pub_date: Option<String>,
+    /// The artifact to download, usually a `.dmg`.
+    pub url: String,
+    pub sha256: String,
+}
+
+/// Sent with the `update-available` event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UpdateInfo {
+    pub current_version: String,
+    pub version: String,
+    pub notes: String,
+    pub pub_date: Option<String>,
+}
+
+pub fn skipped_version() -> Option<String> {
+    settings::load()
+        .ok()
+        .and_then(|settings| settings.updates.skipped_version)
+}
+
+/// Stops [`check`] from reporting `version`. Newer releases are reported again.
+pub fn skip_version(version: &str) -> Result<(), String> {
+    settings::update(|settings| settings.updates.skipped_version = Some(version.to_string()))
+        .map(|_| ())
+}
+
+fn parse_version(version: &str) -> Result<Version, String> {
+    Version::parse(version.trim().trim_start_matches('v'))
+        .map_err(|e| format!("Invalid version {}: {}", version, e))
+}
+
+/// Reads the release manifest from `source`, an `http(s)` URL or a local file path.
+pub async fn fetch_manifest(
+    client: &reqwest::Client,
+    source: &str,
+) -> Result<ReleaseManifest, String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        client
+            .get(source)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Failed to fetch update manifest: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Invalid update manifest: {}", e))
+    } else {
+        let path = source.trim_start_matches("file://");
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read update manifest: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Invalid update manifest: {}", e))
+    }
+}
+
+/// Returns the release in `source` if it is newer than `current_version` and hasn't been
+/// skipped.
+pub async fn check(
+    client: &reqwest::Client,
+    source: &str,
+    current_version: &str,
+) -> Result<Option<UpdateInfo>, String> {
+    let release = fetch_manifest(client, source).await?;
+    let latest = parse_version(&release.version)?;
+    if latest <= parse_version(current_version)? {
+        return Ok(None);
+    }
+
+    let skipped = skipped_version().and_then(|version| parse_version(&version).ok());
+    if skipped.as_ref() == Some(&latest) {
+        return Ok(None);
+    }
+
+    Ok(Some(UpdateInfo {
+        current_version: current_version.to_string(),
+        version: latest.to_string(),
+        notes: release.notes,
+        pub_date: release.pub_date,
+    }))
+}
+
+/// Downloads the artifact of release `version` into the app data dir and verifies its
+/// checksum. A file that fails verification is deleted.
+pub async fn download(
+    client: &reqwest::Client,
+    source: &str,
+    version: &str,
+    mut on_progress: impl FnMut(InstallProgress),
+) -> Result<PathBuf, String> {
+    let result = download_release(client, source, version, &mut on_progress).await;
+    match &result {
+        Ok(_) => on_progress(InstallProgress::Completed),
+        Err(e) => on_progress(InstallProgress::Error { message: e.clone() }),
+    }
+    result
+}
+
+async fn download_release(
+    client: &reqwest::Client,
+    source: &str,
+    version: &str,
+    on_progress: &mut impl FnMut(InstallProgress),
+) -> Result<PathBuf, String> {
+    on_progress(InstallProgress::Preparing);
+    let release = fetch_manifest(client, source).await?;
+    if parse_version(&release.version)? != parse_version(version)? {
+        return Err(format!(
+            "Version {} is no longer the latest release, check for updates again",
+            version
+        ));
+    }
+    if !installer::is_sha256(&release.sha256) {
+        return Err(format!("Release {} has no valid checksum", release.version));
+    }
+
+    let file_name = release
+        .url
+        .split(['?', '#'])
+        .next()
+        .and_then(|url| url.rsplit('/').next())
+        .filter(|name| !name.is_empty() && !name.starts_with('.'))
+        .unwrap_or("Comet.dmg");
+    let dir = paths::app_data_dir().join("updates").join(&release.version);
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let target = dir.join(file_name);
+
+    let verified = installer::download(client, &release.url, &target, on_progress)
+        .await
+        .and_then(|digest| {
+            on_progress(InstallProgress::Verifying);
+            if digest.eq_ignore_ascii_case(&release.sha256) {
+                Ok(target.clone())
+            } else {
+                Err(format!(
+                    "Update checksum mismatch: expected {}, got {}",
+                    release.sha256, digest
+                ))
+            }
+        });
+    if verified.is_err() {
+        let _ = fs::remove_file(&target);
+    }
+    verified
+}
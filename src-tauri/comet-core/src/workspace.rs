@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::paths;
+use crate::persist;
+use crate::settings;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+}
+
+/// The workspace list together with the active workspace, which is kept in [`settings`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceState {
+    pub active_workspace: Option<String>,
+    pub workspaces: Vec<Workspace>,
+}
+
+// What `state.json` holds.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredWorkspaces {
+    workspaces: Vec<Workspace>,
+}
+
+fn get_workspaces_dir() -> PathBuf {
+    let mut path = paths::app_data_dir();
+    path.push("workspaces");
+    fs::create_dir_all(&path).expect("Failed to create directory");
+    path
+}
+
+fn get_workspace_state_file() -> PathBuf {
+    let mut path = get_workspaces_dir();
+    path.push("state.json");
+    path
+}
+
+pub fn get_workspace_tabs_dir(workspace_id: &str) -> PathBuf {
+    let mut path = get_workspaces_dir();
+    path.push(workspace_id);
+    path.push("tabs");
+    fs::create_dir_all(&path).expect("Failed to create directory");
+    path
+}
+
+/// Where the revisions of a workspace's tabs are kept, next to its tabs.
+pub fn get_workspace_history_dir(workspace_id: &str) -> PathBuf {
+    let mut path = get_workspaces_dir();
+    path.push(workspace_id);
+    path.push("history");
+    path
+}
+
+/// Loads the workspace list, creating the default workspace on first run.
+pub fn load_workspaces() -> Result<WorkspaceState, String> {
+    let state_file = get_workspace_state_file();
+    // Loading the settings first moves a legacy `active_workspace` out of `state.json`.
+    let active_workspace = settings::load()?.workspace.active_workspace;
+
+    if let Some(stored) = persist::read_json::<StoredWorkspaces>(&state_file)? {
+        Ok(WorkspaceState {
+            active_workspace,
+            workspaces: stored.workspaces,
+        })
+    } else {
+        let default_workspace = Workspace {
+            id: "default".to_string(),
+            name: "Default".to_string(),
+            path: get_workspace_tabs_dir("default")
+                .to_string_lossy()
+                .to_string(),
+        };
+
+        let state = WorkspaceState {
+            active_workspace: Some("default".to_string()),
+            workspaces: vec![default_workspace],
+        };
+
+        save_workspace_state(&state)?;
+        settings::update(|settings| {
+            settings.workspace.active_workspace = state.active_workspace.clone()
+        })?;
+        Ok(state)
+    }
+}
+
+/// Creates a workspace whose id is the lowercased, dash-separated name.
+pub fn create_workspace(name: &str) -> Result<Workspace, String> {
+    let workspace_id = name.to_lowercase().replace(" ", "-");
+    let workspace = Workspace {
+        id: workspace_id.clone(),
+        name: name.to_string(),
+        path: get_workspace_tabs_dir(&workspace_id)
+            .to_string_lossy()
+            .to_string(),
+    };
+
+    let mut state = load_workspaces()?;
+    if state.workspaces.iter().any(|w| w.id == workspace_id) {
+        return Err(format!("Workspace with name '{}' already exists", name));
+    }
+
+    state.workspaces.push(workspace.clone());
+    save_workspace_state(&state)?;
+
+    Ok(workspace)
+}
+
+/// Deletes a workspace and its tabs. The active and the last workspace cannot be deleted.
+pub fn delete_workspace(workspace_id: &str) -> Result<(), String> {
+    let mut state = load_workspaces()?;
+
+    if state.workspaces.len() <= 1 {
+        return Err("Cannot delete the last workspace".to_string());
+    }
+
+    if let Some(active) = &state.active_workspace {
+        if active == workspace_id {
+            return Err("Cannot delete the active workspace".to_string());
+        }
+    }
+
+    let mut workspace_path = get_workspaces_dir();
+    workspace_path.push(workspace_id);
+
+    if workspace_path.exists() {
+        fs::remove_dir_all(workspace_path).map_err(|e| e.to_string())?;
+    }
+
+    state.workspaces.retain(|w| w.id != workspace_id);
+    save_workspace_state(&state)?;
+
+    Ok(())
+}
+
+pub fn set_active_workspace(workspace_id: &str) -> Result<(), String> {
+    let state = load_workspaces()?;
+
+    if !state.workspaces.iter().any(|w| w.id == workspace_id) {
+        return Err(format!("Workspace '{}' not found", workspace_id));
+    }
+
+    settings::update(|settings| {
+        settings.workspace.active_workspace = Some(workspace_id.to_string())
+    })?;
+
+    Ok(())
+}
+
+pub fn rename_workspace(workspace_id: &str, new_name: &str) -> Result<(), String> {
+    let mut state = load_workspaces()?;
+
+    if state
+        .workspaces
+        .iter()
+        .any(|w| w.name.to_lowercase() == new_name.to_lowercase() && w.id != workspace_id)
+    {
+        return Err(format!("Workspace with name '{}' already exists", new_name));
+    }
+
+    if let Some(workspace) = state.workspaces.iter_mut().find(|w| w.id == workspace_id) {
+        workspace.name = new_name.to_string();
+        save_workspace_state(&state)?;
+        Ok(())
+    } else {
+        Err(format!("Workspace '{}' not found", workspace_id))
+    }
+}
+
+fn save_workspace_state(state: &WorkspaceState) -> Result<(), String> {
+    let state_file = get_workspace_state_file();
+    let stored = StoredWorkspaces {
+        workspaces: state.workspaces.clone(),
+    };
+    persist::write_json(&state_file, &stored)
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use comet_core::auto_execute;
+use comet_core::ApiType;
+use common::TestEnv;
+
+#[tokio::test]
+async fn toggle_auto_execute_across_api_types() {
+    let env = TestEnv::start(ApiType::Hydrogen).await;
+
+    assert!(auto_execute::is_auto_execute_enabled().unwrap());
+    auto_execute::save_auto_execute_file("esp", "print('esp')", ApiType::Hydrogen).unwrap();
+    auto_execute::save_auto_execute_file("fly.luau", "print('fly')", ApiType::Hydrogen).unwrap();
+    assert_eq!(
+        env.auto_execute_files(ApiType::Hydrogen),
+        ["esp.lua", "fly.luau"]
+    );
+
+    assert!(!auto_execute::toggle_auto_execute(ApiType::Hydrogen).unwrap());
+    assert!(!auto_execute::is_auto_execute_enabled().unwrap());
+    assert!(env.auto_execute_files(ApiType::Hydrogen).is_empty());
+
+    let status = env.state.set_api_type(ApiType::MacSploit).await;
+    assert_eq!(status.api_type, ApiType::MacSploit);
+    assert_eq!(status.current_port, env.mock.macsploit_ports()[0]);
+    assert!(!status.is_connected);
+
+    assert!(auto_execute::toggle_auto_execute(ApiType::MacSploit).unwrap());
+    assert_eq!(
+        env.auto_execute_files(ApiType::MacSploit),
+        ["esp.lua", "fly.luau"]
+    );
+    assert!(env.auto_execute_files(ApiType::Hydrogen).is_empty());
+
+    auto_execute::rename_auto_execute_file("esp.lua", "wallhack", ApiType::MacSploit).unwrap();
+    auto_execute::delete_auto_execute_file("fly.luau", ApiType::MacSploit).unwrap();
+    assert_eq!(env.auto_execute_files(ApiType::MacSploit), ["wallhack.lua"]);
+
+    let files = auto_execute::get_auto_execute_files().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].name, "wallhack.lua");
+    assert_eq!(files[0].content, "print('esp')");
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use comet_core::brand::BrandRegistry;
+use comet_core::config::CometConfig;
+use comet_core::installer::InstallManifest;
+use comet_core::{paths, ApiType};
+use common::TestEnv;
+
+#[tokio::test]
+async fn brands_resolve_names_paths_and_installers() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+    let registry = BrandRegistry::from_config(CometConfig::defaults());
+    let home = paths::home_dir().unwrap();
+
+    let comet = registry.for_product("Comet");
+    assert_eq!(comet.id, "default");
+    assert_eq!(comet.executor_name(), "Hydrogen");
+    assert_eq!(comet.notifications().title, "Comet");
+    assert_eq!(
+        comet.key_file().unwrap(),
+        home.join("Library/Application Support/Hydrogen/key.txt")
+    );
+    assert_eq!(
+        comet.key_validation_url(),
+        Some("https://www.hydrogen.lat/api/validate-key")
+    );
+
+    // Nothing publishes release manifests yet, so no brand checks for updates.
+    assert_eq!(comet.update_manifest(), None);
+
+    let ronix = registry.for_product("ronix");
+    assert_eq!(ronix.key_validation_url(), None);
+    assert_eq!(ronix.data_dir().unwrap(), home.join("Ronix"));
+    assert_eq!(ronix.settings_dir().unwrap(), home.join(".ronix"));
+    assert_eq!(
+        ronix.dylib_path(),
+        paths::applications_dir().join("Ronix.app/Contents/MacOS/Ronix.dylib")
+    );
+    assert_eq!(registry.for_product("Unknown").id, "default");
+
+    let manifest = InstallManifest::pinned();
+    for id in ["default", "hydrogen", "ronix"] {
+        let brand = registry.get(id).unwrap();
+        assert!(manifest.for_url(brand.install_url()).is_ok());
+    }
+    assert!(manifest.for_url("https://example.com/install").is_err());
+}
@@ -0,0 +1,110 @@
+// Each test binary uses only some of these helpers.
+#![allow(dead_code)]
+
+use comet_core::auto_execute;
+use comet_core::executor_settings::ExecutorSettings;
+use comet_core::mock_executor::{MockConfig, MockExecutor};
+use comet_core::tabs::Tab;
+use comet_core::{paths, ApiType, AppState};
+use tempfile::TempDir;
+use tokio::sync::{Mutex, MutexGuard};
+
+// The data root is process-wide, so tests that touch it run one at a time.
+static DATA_ROOT_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// A temporary data root, an in-process mock executor and an `AppState` pointed at it.
+pub struct TestEnv {
+    pub state: AppState,
+    pub mock: MockExecutor,
+    _root: TempDir,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl TestEnv {
+    pub async fn start(api_type: ApiType) -> Self {
+        Self::with_mock(api_type, MockConfig::default()).await
+    }
+
+    pub async fn with_mock(api_type: ApiType, config: MockConfig) -> Self {
+        let guard = DATA_ROOT_LOCK.lock().await;
+        let root = TempDir::new().expect("Failed to create temp dir");
+        paths::set_data_root(Some(root.path().to_path_buf()));
+
+        let mock = MockExecutor::start(MockConfig {
+            hydrogen_ports: vec![0],
+            macsploit_ports: vec![0],
+            ..config
+        })
+        .await
+        .expect("Failed to start mock executor");
+
+        let mut endpoints = ExecutorSettings::default().endpoints();
+        for (api_type, port) in [
+            (ApiType::Hydrogen, mock.hydrogen_ports()[0]),
+            (ApiType::MacSploit, mock.macsploit_ports()[0]),
+        ] {
+            let endpoint = endpoints.get_mut(&api_type).unwrap();
+            endpoint.min_port = port;
+            endpoint.max_port = port;
+        }
+
+        Self {
+            state: AppState::with_endpoints(api_type, endpoints),
+            mock,
+            _root: root,
+            _guard: guard,
+        }
+    }
+
+    pub fn auto_execute_files(&self, api_type: ApiType) -> Vec<String> {
+        let dir = auto_execute::get_auto_execute_dir(api_type).unwrap();
+        let mut names = std::fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+}
+
+impl Drop for TestEnv {
+    fn drop(&mut self) {
+        self.mock.shutdown();
+        paths::set_data_root(None);
+    }
+}
+
+/// A Lua tab with the given id, title and content.
+pub fn tab(id: &str, title: &str, content: &str) -> Tab {
+    Tab {
+        id: id.to_string(),
+        title: title.to_string(),
+        content: content.to_string(),
+        language: "lua".to_string(),
+    }
+}
+
+/// Serves `body` over HTTP on a local port and returns its URL.
+pub async fn serve_file(body: &'static [u8]) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/install", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request).await;
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let _ = stream.write_all(head.as_bytes()).await;
+            let _ = stream.write_all(body).await;
+        }
+    });
+    url
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use comet_core::config::{self, CometConfig, ConfigStore};
+use comet_core::ApiType;
+use common::TestEnv;
+
+// Writing a file can be seen half-done first, so wait for the change that matches.
+async fn wait_for<T>(
+    changed: &mut tokio::sync::mpsc::UnboundedReceiver<T>,
+    matches: impl Fn(&T) -> bool,
+) -> T {
+    loop {
+        let change = tokio::time::timeout(std::time::Duration::from_secs(2), changed.recv())
+            .await
+            .expect("No matching change was reported")
+            .unwrap();
+        if matches(&change) {
+            return change;
+        }
+    }
+}
+
+#[tokio::test]
+async fn config_layers_validate_and_reload() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+    let store = ConfigStore::load(Some("Ronix".to_string()));
+    assert!(store.current().errors.is_empty());
+    assert_eq!(store.brand().notifications().title, "Ronix");
+
+    let (changes, mut changed) = tokio::sync::mpsc::unbounded_channel();
+    let _watcher = config::watch(store.clone(), move |loaded| {
+        let _ = changes.send(loaded);
+    })
+    .unwrap();
+
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    std::fs::write(
+        config::override_path(),
+        r#"{"brands":{"ronix":{"notifications":{"title":"Ronix Beta"}}}}"#,
+    )
+    .unwrap();
+    wait_for(&mut changed, |loaded| loaded.errors.is_empty()).await;
+    assert_eq!(store.brand().notifications().title, "Ronix Beta");
+    assert_eq!(
+        store.brand().install_url(),
+        "https://www.ronixmac.lol/install"
+    );
+
+    std::fs::write(
+        config::override_path(),
+        r#"{"brands":{"ronix":{"installUrl":"http://example.com","dataFolder":"../x"}}}"#,
+    )
+    .unwrap();
+    let loaded = wait_for(&mut changed, |loaded| {
+        loaded
+            .errors
+            .iter()
+            .any(|e| e.contains("brands.ronix.dataFolder"))
+    })
+    .await;
+    assert_eq!(loaded.errors.len(), 1);
+    assert!(loaded.errors[0].contains("brands.ronix.installUrl"));
+    assert_eq!(store.brand().notifications().title, "Ronix");
+    assert_eq!(
+        store.brand().install_url(),
+        "https://www.ronixmac.lol/install"
+    );
+
+    std::fs::write(
+        config::override_path(),
+        r#"{"brands":{"ronix":{"typo":1}}}"#,
+    )
+    .unwrap();
+    wait_for(&mut changed, |loaded| {
+        loaded
+            .errors
+            .iter()
+            .any(|e| e.contains("unknown field `typo`"))
+    })
+    .await;
+    assert!(CometConfig::defaults().validate().is_ok());
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use comet_core::auto_execute;
+use comet_core::daemon;
+use comet_core::ipc::{self, Request};
+use comet_core::tabs;
+use comet_core::ApiType;
+use common::{tab, TestEnv};
+use std::os::unix::fs::PermissionsExt;
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+#[tokio::test]
+async fn daemon_answers_control_requests() {
+    let env = TestEnv::start(ApiType::Hydrogen).await;
+    let path = ipc::socket_path();
+    let shutdown = Arc::new(Notify::new());
+    let daemon = tokio::spawn(daemon::run(env.state.clone(), shutdown));
+
+    let mut status = Err(String::new());
+    for _ in 0..50 {
+        status = ipc::send(&Request::Status).await;
+        if status.is_ok() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(status.unwrap()["api_type"], "Hydrogen");
+    assert!(daemon::run(env.state.clone(), Arc::new(Notify::new()))
+        .await
+        .is_err());
+
+    let result = ipc::send(&Request::Execute {
+        script: "print('ipc')".to_string(),
+    })
+    .await
+    .unwrap();
+    assert_eq!(result["success"], true);
+    assert_eq!(env.mock.received()[0].script, "print('ipc')");
+
+    let status = ipc::send(&Request::SetApiType {
+        api_type: ApiType::MacSploit,
+    })
+    .await
+    .unwrap();
+    assert_eq!(status["api_type"], "MacSploit");
+    assert_eq!(env.state.status().await.api_type, ApiType::MacSploit);
+
+    auto_execute::save_auto_execute_file("esp", "print('esp')", ApiType::Hydrogen).unwrap();
+    let copied = ipc::send(&Request::SyncAutoExecute).await.unwrap();
+    assert_eq!(copied, 1);
+    assert_eq!(env.auto_execute_files(ApiType::MacSploit), ["esp.lua"]);
+
+    let history = ipc::send(&Request::History).await.unwrap();
+    assert_eq!(history[0]["content"], "print('ipc')");
+    assert_eq!(history[0]["success"], true);
+
+    let workspaces = ipc::send(&Request::ListWorkspaces).await.unwrap();
+    assert_eq!(workspaces["active_workspace"], "default");
+    tabs::save_tab("default", &tab("a", "main.lua", "print(1)")).unwrap();
+    let tabs = ipc::send(&Request::ListTabs { workspace_id: None })
+        .await
+        .unwrap();
+    assert_eq!(tabs[0]["title"], "main.lua");
+
+    let token = std::fs::read_to_string(ipc::token_path()).unwrap();
+    std::fs::write(ipc::token_path(), "wrong").unwrap();
+    assert_eq!(
+        ipc::send(&Request::Status).await.unwrap_err(),
+        "Invalid token"
+    );
+    std::fs::write(ipc::token_path(), token).unwrap();
+
+    let mode = |path: std::path::PathBuf| std::fs::metadata(path).unwrap().permissions().mode();
+    assert_eq!(mode(ipc::control_dir()) & 0o777, 0o700);
+
+    ipc::send(&Request::Shutdown).await.unwrap();
+    tokio::time::timeout(std::time::Duration::from_secs(2), daemon)
+        .await
+        .expect("Daemon did not stop")
+        .unwrap()
+        .unwrap();
+    assert!(!path.exists());
+    assert!(!ipc::token_path().exists());
+
+    // Served by the app, which has no shutdown to trigger.
+    let socket = ipc::bind().await.unwrap();
+    assert_eq!(mode(ipc::token_path()) & 0o777, 0o600);
+    let app = tokio::spawn(ipc::serve(socket, env.state.clone(), None));
+    assert_eq!(
+        ipc::send(&Request::Shutdown).await.unwrap_err(),
+        "Shutdown is only available in comet-daemon"
+    );
+    assert!(ipc::send(&Request::Status).await.is_ok());
+    app.abort();
+}
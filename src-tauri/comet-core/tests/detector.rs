@@ -0,0 +1,56 @@
+mod common;
+
+use comet_core::detector;
+use comet_core::{paths, ApiType};
+use common::TestEnv;
+
+#[tokio::test]
+async fn detection_reports_conflicts_and_changes() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+    let applications = paths::applications_dir();
+    let macos_dir = detector::roblox_macos_dir();
+    std::fs::create_dir_all(&macos_dir).unwrap();
+
+    let report = detector::detect();
+    assert!(report.roblox_installed);
+    assert!(report.executors.is_empty());
+    assert!(!report.conflict);
+
+    let bundle = applications.join("MacSploit.app/Contents");
+    std::fs::create_dir_all(&bundle).unwrap();
+    std::fs::write(
+        bundle.join("Info.plist"),
+        "<dict>\n\t<key>CFBundleShortVersionString</key>\n\t<string>2.6.1</string>\n</dict>",
+    )
+    .unwrap();
+    std::fs::write(macos_dir.join("RobloxPlayer.copy"), "").unwrap();
+
+    let report = detector::detect();
+    assert_eq!(report.recommended, ApiType::Hydrogen);
+    assert_eq!(report.executors.len(), 2);
+    let macsploit = &report.executors[1];
+    assert_eq!(macsploit.api_type, ApiType::MacSploit);
+    assert_eq!(macsploit.version.as_deref(), Some("2.6.1"));
+    assert!(!macsploit.injected);
+    assert!(!report.conflict);
+
+    let (changes, mut changed) = tokio::sync::mpsc::unbounded_channel();
+    let _watcher = detector::watch(move |report| {
+        let _ = changes.send(report);
+    })
+    .unwrap();
+
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    std::fs::write(macos_dir.join("macsploit.dylib"), "").unwrap();
+    let report = loop {
+        let report = tokio::time::timeout(std::time::Duration::from_secs(2), changed.recv())
+            .await
+            .expect("No detection-changed report")
+            .unwrap();
+        if report.conflict {
+            break report;
+        }
+    };
+    assert_eq!(report.recommended, ApiType::MacSploit);
+    assert!(report.executors.iter().all(|executor| executor.injected));
+}
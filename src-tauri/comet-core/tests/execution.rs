@@ -0,0 +1,242 @@
+mod common;
+
+use comet_core::backend::{ExecuteError, ExecutorOutput, OutputKind};
+use comet_core::connection::{ConnectionManager, ExecutionTarget};
+use comet_core::discovery::DiscoveryOptions;
+use comet_core::execution_history::{self, ExecutionRecord};
+use comet_core::executor_settings::ExecutorSettings;
+use comet_core::mock_executor::{MockConfig, MockExecutor, Stage};
+use comet_core::ApiType;
+use common::TestEnv;
+use tokio::sync::broadcast;
+
+#[tokio::test]
+async fn send_script_to_hydrogen_and_record_history() {
+    let env = TestEnv::start(ApiType::Hydrogen).await;
+    let port = env.mock.hydrogen_ports()[0];
+
+    let result = env.state.execute("print('hello')").await.unwrap();
+    assert!(result.success);
+    assert_eq!(result.port, port);
+    assert_eq!(result.status, Some(200));
+
+    let status = env.state.status().await;
+    assert!(status.is_connected);
+    assert_eq!(status.port, Some(port));
+
+    let received = env.mock.received();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].stage, Stage::Execute);
+    assert_eq!(received[0].script, "print('hello')");
+
+    for (id, success) in [("1", true), ("2", false), ("3", true)] {
+        execution_history::save_execution_record(
+            ExecutionRecord {
+                id: id.to_string(),
+                timestamp: 0,
+                content: "print('hello')".to_string(),
+                success,
+                error: (!success).then(|| "HTTP 500".to_string()),
+            },
+            2,
+        )
+        .unwrap();
+    }
+    let history = execution_history::load_execution_history().unwrap();
+    let ids = history
+        .iter()
+        .map(|record| record.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["3", "2"]);
+
+    execution_history::clear_execution_history().unwrap();
+    assert!(execution_history::load_execution_history()
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn injected_failures_surface_as_results_and_errors() {
+    let env = TestEnv::with_mock(
+        ApiType::Hydrogen,
+        MockConfig {
+            faults: vec![
+                "execute:500@1".parse().unwrap(),
+                "execute:drop@2".parse().unwrap(),
+            ],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let result = env.state.execute("error('boom')").await.unwrap();
+    assert!(!result.success);
+    assert_eq!(result.status, Some(500));
+    assert_eq!(
+        result.error_message().as_deref(),
+        Some("HTTP 500: Injected failure")
+    );
+
+    let error = env.state.execute("print(1)").await.unwrap_err();
+    assert!(matches!(error, ExecuteError::ConnectionError(_)));
+    assert!(!env.state.status().await.is_connected);
+
+    let result = env.state.execute("print(2)").await.unwrap();
+    assert!(result.success);
+    assert_eq!(env.mock.received().len(), 1);
+}
+
+#[tokio::test]
+async fn send_script_to_macsploit_streams_output() {
+    let env = TestEnv::start(ApiType::MacSploit).await;
+    let mut output = env.state.connection.subscribe_output();
+
+    let result = env.state.execute("print('hi')").await.unwrap();
+    assert!(result.success);
+    assert_eq!(result.port, env.mock.macsploit_ports()[0]);
+
+    let message = tokio::time::timeout(std::time::Duration::from_secs(2), output.recv())
+        .await
+        .expect("No executor output")
+        .unwrap();
+    assert_eq!(message.kind, OutputKind::Print);
+    assert_eq!(message.message, "Executed 11 bytes");
+    assert_eq!(message.execution_id, result.execution_id);
+    assert_eq!(message.script_preview.as_deref(), Some("print('hi')"));
+
+    let instances = env.state.connection.list_instances().await;
+    assert_eq!(instances.len(), 1);
+    assert_eq!(instances[0].api_type, ApiType::MacSploit);
+}
+
+async fn next_output(output: &mut broadcast::Receiver<ExecutorOutput>) -> ExecutorOutput {
+    tokio::time::timeout(std::time::Duration::from_secs(2), output.recv())
+        .await
+        .expect("No executor output")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn back_to_back_macsploit_output_is_not_blamed_on_the_next_script() {
+    let env = TestEnv::start(ApiType::MacSploit).await;
+    let mut output = env.state.connection.subscribe_output();
+
+    let first = env.state.execute("print('one')").await.unwrap();
+    let second = env.state.execute("print('two')").await.unwrap();
+    assert_ne!(first.execution_id, second.execution_id);
+
+    // Whether the first reply lands before the second script starts is a race, but neither reply
+    // may be blamed on the second script.
+    let early = next_output(&mut output).await;
+    assert!(early.execution_id.is_none() || early.execution_id == first.execution_id);
+    // The second script started before the first went quiet, so its output can't be told apart.
+    let late = next_output(&mut output).await;
+    assert_eq!(late.execution_id, None);
+    assert_eq!(late.script_preview, None);
+
+    tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+    let third = env.state.execute("print('three')").await.unwrap();
+    let settled = next_output(&mut output).await;
+    assert_eq!(settled.execution_id, third.execution_id);
+    assert_eq!(settled.script_preview.as_deref(), Some("print('three')"));
+}
+
+#[tokio::test]
+async fn discovery_scans_a_full_closed_range_within_its_timeouts() {
+    let options = DiscoveryOptions::default();
+    let mut endpoints = ExecutorSettings::default().endpoints();
+    let endpoint = endpoints.get_mut(&ApiType::Hydrogen).unwrap();
+    endpoint.host = "127.0.0.1".to_string();
+    // Below the ephemeral range, so the mock executors of other tests never land here.
+    endpoint.min_port = 20000;
+    endpoint.max_port = 21023;
+    let connection =
+        ConnectionManager::new(ApiType::Hydrogen, endpoints).with_discovery_options(options);
+
+    let started = std::time::Instant::now();
+    assert!(connection.discover().await.is_empty());
+    let bound =
+        std::time::Duration::from_millis(options.connect_timeout_ms + options.handshake_timeout_ms);
+    assert!(
+        started.elapsed() < bound,
+        "scan took {:?}",
+        started.elapsed()
+    );
+}
+
+// Two Hydrogen mocks on neighbouring fixed ports, the second failing every execution. Fixed
+// ports below the ephemeral range keep the configured port range small.
+async fn start_neighbouring_mocks() -> (MockExecutor, MockExecutor) {
+    for base in (21100..21500).step_by(10) {
+        let Ok(healthy) = MockExecutor::start(MockConfig {
+            hydrogen_ports: vec![base],
+            macsploit_ports: vec![],
+            ..Default::default()
+        })
+        .await
+        else {
+            continue;
+        };
+        if let Ok(failing) = MockExecutor::start(MockConfig {
+            hydrogen_ports: vec![base + 1],
+            macsploit_ports: vec![],
+            faults: vec!["execute:500".parse().unwrap()],
+            ..Default::default()
+        })
+        .await
+        {
+            return (healthy, failing);
+        }
+    }
+    panic!("No free ports for the mock executors");
+}
+
+#[tokio::test]
+async fn scripts_are_sent_to_chosen_instances() {
+    let (healthy, failing) = start_neighbouring_mocks().await;
+    let (good, bad) = (healthy.hydrogen_ports()[0], failing.hydrogen_ports()[0]);
+    let mut endpoints = ExecutorSettings::default().endpoints();
+    let endpoint = endpoints.get_mut(&ApiType::Hydrogen).unwrap();
+    endpoint.min_port = good;
+    endpoint.max_port = bad;
+    let connection = ConnectionManager::new(ApiType::Hydrogen, endpoints);
+
+    let results = connection
+        .send_to("print('all')", ExecutionTarget::All)
+        .await;
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].port, good);
+    assert!(results[0].success && results[0].error.is_none());
+    assert_eq!(results[1].port, bad);
+    assert!(!results[1].success);
+    assert_eq!(results[1].result.as_ref().unwrap().status, Some(500));
+
+    let results = connection
+        .send_to("print('one')", ExecutionTarget::Port { port: good })
+        .await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].success);
+
+    let results = connection
+        .send_to(
+            "print('some')",
+            ExecutionTarget::Ports {
+                ports: vec![bad + 1, bad, bad],
+            },
+        )
+        .await;
+    assert_eq!(
+        results.iter().map(|result| result.port).collect::<Vec<_>>(),
+        [bad, bad + 1]
+    );
+    assert!(!results[0].success && results[0].result.is_some());
+    assert!(results[1].result.is_none());
+    assert!(results[1].error.as_ref().unwrap().contains("outside"));
+
+    let received = healthy
+        .received()
+        .into_iter()
+        .map(|received| received.script)
+        .collect::<Vec<_>>();
+    assert_eq!(received, ["print('all')", "print('one')"]);
+}
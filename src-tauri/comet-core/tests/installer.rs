@@ -0,0 +1,113 @@
+mod common;
+
+use comet_core::installer::{self, InstallProgress, InstallerEntry};
+use comet_core::{paths, ApiType};
+use common::{serve_file, TestEnv};
+
+#[tokio::test]
+async fn installer_is_verified_before_it_runs() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+    let client = reqwest::Client::new();
+    let url = serve_file(b"echo installed\n").await;
+    let sha256 = "0".repeat(64);
+
+    let mut progress = Vec::new();
+    let mut ran = false;
+    let error = installer::install(
+        &client,
+        &InstallerEntry {
+            url: url.clone(),
+            sha256,
+        },
+        |_| {
+            ran = true;
+            Ok(String::new())
+        },
+        |step| progress.push(step),
+    )
+    .await
+    .unwrap_err();
+    assert!(!ran);
+    assert!(error.starts_with("Installer checksum mismatch"));
+    assert_eq!(
+        progress.last(),
+        Some(&InstallProgress::Error { message: error })
+    );
+
+    let error = installer::install(
+        &client,
+        &InstallerEntry {
+            url: url.clone(),
+            sha256: "abc".to_string(),
+        },
+        |_| Ok(String::new()),
+        |_| {},
+    )
+    .await
+    .unwrap_err();
+    assert!(error.starts_with("Invalid checksum pinned"));
+
+    let mut progress = Vec::new();
+    let mut ran = false;
+    installer::install(
+        &client,
+        &InstallerEntry {
+            url: url.clone(),
+            sha256: String::new(),
+        },
+        |_| {
+            ran = true;
+            Ok(String::new())
+        },
+        |step| progress.push(step),
+    )
+    .await
+    .unwrap();
+    assert!(ran);
+    assert!(!progress.contains(&InstallProgress::Verifying));
+
+    let mut progress = Vec::new();
+    let mut script = String::new();
+    installer::install(
+        &client,
+        &InstallerEntry {
+            url,
+            sha256: "99e966666677428551eaa369f4266e334592025a7e6a54aa87d3933c7f0e3a80".to_string(),
+        },
+        |path| {
+            script = std::fs::read_to_string(path).unwrap();
+            Ok("done".to_string())
+        },
+        |step| progress.push(step),
+    )
+    .await
+    .unwrap();
+    assert_eq!(script, "echo installed\n");
+    assert_eq!(
+        progress,
+        [
+            InstallProgress::Preparing,
+            InstallProgress::Downloading {
+                downloaded: 0,
+                total: Some(15)
+            },
+            InstallProgress::Downloading {
+                downloaded: 15,
+                total: Some(15)
+            },
+            InstallProgress::Verifying,
+            InstallProgress::Installing,
+            InstallProgress::Completed,
+        ]
+    );
+
+    let log = installer::read_log().unwrap();
+    assert!(log.contains("Installer checksum mismatch"));
+    assert!(log.contains("No checksum pinned, running unverified"));
+    assert!(log.contains("installer: done"));
+    assert!(log.contains("Installation completed"));
+    assert!(std::fs::read_dir(paths::app_data_dir().join("installers"))
+        .unwrap()
+        .next()
+        .is_none());
+}
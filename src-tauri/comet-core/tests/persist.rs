@@ -0,0 +1,85 @@
+mod common;
+
+use comet_core::execution_history::{self, ExecutionRecord};
+use comet_core::persist;
+use comet_core::tabs;
+use comet_core::workspace;
+use comet_core::{paths, ApiType};
+use common::{tab, TestEnv};
+
+#[tokio::test]
+async fn state_files_fall_back_to_their_backup() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+
+    let tabs = [
+        tab("a", "a.lua", "print('a')"),
+        tab("b", "b.lua", "print('b')"),
+    ];
+    for tab in &tabs {
+        tabs::save_tab("default", tab).unwrap();
+    }
+    for order in [["a", "b"], ["b", "a"]] {
+        tabs::save_tab_state(
+            "default",
+            Some(order[0].to_string()),
+            order.map(str::to_string).to_vec(),
+            tabs.to_vec(),
+        )
+        .unwrap();
+    }
+
+    let tabs_dir = workspace::get_workspace_tabs_dir("default");
+    std::fs::write(tabs_dir.join("state.json"), r#"{"active_tab": "#).unwrap();
+    let order = tabs::load_tabs("default")
+        .unwrap()
+        .into_iter()
+        .map(|tab| tab.id)
+        .collect::<Vec<_>>();
+    assert_eq!(order, ["a", "b"]);
+    assert_eq!(
+        tabs::get_tab_state("default")
+            .unwrap()
+            .active_tab
+            .as_deref(),
+        Some("a")
+    );
+    let leftovers = std::fs::read_dir(&tabs_dir)
+        .unwrap()
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+        .count();
+    assert_eq!(leftovers, 0);
+
+    for id in ["first", "second"] {
+        execution_history::save_execution_record(
+            ExecutionRecord {
+                id: id.to_string(),
+                timestamp: 0,
+                content: String::new(),
+                success: true,
+                error: None,
+            },
+            10,
+        )
+        .unwrap();
+    }
+    let history_file = paths::app_data_dir()
+        .join("execution_history")
+        .join("history.json");
+    std::fs::write(&history_file, "[{").unwrap();
+    let history = execution_history::load_execution_history().unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].id, "first");
+
+    // A corrupt file is replaced but never becomes the backup.
+    execution_history::clear_execution_history().unwrap();
+    assert!(execution_history::load_execution_history()
+        .unwrap()
+        .is_empty());
+    let backup = std::fs::read_to_string(persist::backup_path(&history_file)).unwrap();
+    assert!(backup.contains("\"first\""));
+
+    std::fs::write(&history_file, "").unwrap();
+    std::fs::remove_file(persist::backup_path(&history_file)).unwrap();
+    assert!(execution_history::load_execution_history().is_err());
+}
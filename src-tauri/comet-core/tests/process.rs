@@ -0,0 +1,54 @@
+mod common;
+
+use comet_core::process::{self, ProcessEvent, RobloxProcess};
+use comet_core::ApiType;
+use common::TestEnv;
+
+#[tokio::test]
+async fn roblox_processes_are_merged_into_status() {
+    let env = TestEnv::start(ApiType::Hydrogen).await;
+    let output = "  311 01-02:03:04   0.0   1024 /sbin/launchd
+  812    05:10  42.5 524288 /Applications/Roblox.app/Contents/MacOS/RobloxPlayer
+  900 00:02   1.0   2048 /Applications/Roblox.app/Contents/MacOS/RobloxCrashHandler
+";
+    let processes = process::parse_ps_output(output, 1_000_000);
+    assert_eq!(
+        processes,
+        [RobloxProcess {
+            pid: 812,
+            started_at: 1_000_000 - 310_000,
+            cpu_percent: 42.5,
+            memory_bytes: 524288 * 1024,
+        }]
+    );
+
+    let mut events = env.state.subscribe_processes();
+    let mut updates = env.state.subscribe_status();
+    env.state.set_roblox_processes(processes.clone()).await;
+    assert_eq!(
+        events.recv().await.unwrap(),
+        ProcessEvent::Launched {
+            process: processes[0].clone()
+        }
+    );
+    assert_eq!(updates.recv().await.unwrap().roblox_processes, processes);
+
+    let mut later = processes[0].clone();
+    later.started_at += 1000;
+    later.cpu_percent = 3.0;
+    env.state.set_roblox_processes(vec![later]).await;
+    let status = env.state.status().await;
+    assert_eq!(
+        status.roblox_processes[0].started_at,
+        processes[0].started_at
+    );
+    assert_eq!(status.roblox_processes[0].cpu_percent, 3.0);
+    assert!(updates.try_recv().is_err());
+
+    env.state.set_roblox_processes(Vec::new()).await;
+    assert_eq!(
+        events.recv().await.unwrap(),
+        ProcessEvent::Exited { pid: 812 }
+    );
+    assert!(updates.recv().await.unwrap().roblox_processes.is_empty());
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use comet_core::revisions::{self, DiffKind};
+use comet_core::settings;
+use comet_core::tabs;
+use comet_core::ApiType;
+use common::{tab, TestEnv};
+
+#[tokio::test]
+async fn tab_revisions_are_kept_diffed_and_restored() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+    settings::apply(serde_json::json!({ "history": { "max_revisions": 3 } })).unwrap();
+
+    for (title, content) in [
+        ("aim.lua", "a\nb\nc"),
+        ("aim.lua", "a\nB\nc"),
+        ("aim.lua", "a\nB\nc"),
+        ("aimbot.lua", "a\nB\nc"),
+        ("aimbot.lua", "a\nB\nc\nd"),
+        ("aimbot.lua", "x"),
+    ] {
+        tabs::save_tab("default", &tab("aim", title, content)).unwrap();
+    }
+
+    let listed = revisions::list("default", "aim").unwrap();
+    let ids = listed
+        .iter()
+        .map(|revision| revision.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [4, 3, 2]);
+    assert_eq!(listed[0].size, 1);
+    assert_eq!(listed[2].title, "aim.lua");
+
+    let diff = revisions::diff("default", "aim", 2, 3).unwrap();
+    assert_eq!(diff.len(), 4);
+    assert_eq!(diff[3].kind, DiffKind::Added);
+    assert_eq!((diff[3].old_line, diff[3].new_line), (None, Some(4)));
+
+    let diff = revisions::diff_lines("a\nb\nc", "a\nB\nc");
+    let kinds = diff.iter().map(|line| line.kind).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            DiffKind::Unchanged,
+            DiffKind::Removed,
+            DiffKind::Added,
+            DiffKind::Unchanged
+        ]
+    );
+    assert_eq!((diff[3].old_line, diff[3].new_line), (Some(3), Some(3)));
+
+    let restored = tabs::restore_revision("default", "aim", 2).unwrap();
+    assert_eq!(restored.content, "a\nB\nc");
+    assert_eq!(restored.title, "aimbot.lua");
+    assert_eq!(tabs::load_tabs("default").unwrap()[0].content, "a\nB\nc");
+    let ids = revisions::list("default", "aim")
+        .unwrap()
+        .iter()
+        .map(|revision| revision.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [5, 4, 3]);
+    assert!(tabs::restore_revision("default", "aim", 1).is_err());
+
+    tabs::delete_tab("default", "aim").unwrap();
+    assert!(revisions::list("default", "aim").unwrap().is_empty());
+    assert!(revisions::list("default", "../aim").is_err());
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use comet_core::auto_execute;
+use comet_core::discovery::DiscoveryOptions;
+use comet_core::executor_settings::ExecutorSettings;
+use comet_core::scripts;
+use comet_core::settings::{self, SCHEMA_VERSION};
+use comet_core::updater;
+use comet_core::workspace;
+use comet_core::{paths, ApiType};
+use common::TestEnv;
+
+#[tokio::test]
+async fn settings_migrate_legacy_files_and_broadcast_changes() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+
+    let data_dir = paths::app_data_dir();
+    std::fs::write(
+        data_dir.join("tray_config.json"),
+        r#"{"enabled": false, "show_scripts": true, "show_last_script": false, "custom_scripts": []}"#,
+    )
+    .unwrap();
+    std::fs::write(data_dir.join("auto_execute_disabled"), "").unwrap();
+    std::fs::write(data_dir.join("last_script.txt"), "print('last')").unwrap();
+    std::fs::create_dir_all(data_dir.join("workspaces")).unwrap();
+    std::fs::write(
+        data_dir.join("workspaces").join("state.json"),
+        r#"{"active_workspace": "exploits", "workspaces": [
+            {"id": "default", "name": "Default", "path": "default"},
+            {"id": "exploits", "name": "Exploits", "path": "exploits"}
+        ]}"#,
+    )
+    .unwrap();
+
+    let mut changes = settings::subscribe();
+    let migrated = settings::load().unwrap();
+    assert_eq!(migrated.schema_version, SCHEMA_VERSION);
+    assert!(!migrated.tray.enabled && !migrated.tray.show_last_script);
+    assert!(!migrated.auto_execute.enabled);
+    assert_eq!(
+        migrated.workspace.active_workspace.as_deref(),
+        Some("exploits")
+    );
+    for legacy in ["tray_config.json", "auto_execute_disabled"] {
+        assert!(!data_dir.join(legacy).exists(), "{} was kept", legacy);
+    }
+
+    assert!(!auto_execute::is_auto_execute_enabled().unwrap());
+    assert_eq!(scripts::read_last_script().unwrap(), "print('last')");
+    scripts::save_last_script("print('again')").unwrap();
+    assert_eq!(scripts::read_last_script().unwrap(), "print('again')");
+    assert!(changes.try_recv().is_err());
+    let state = workspace::load_workspaces().unwrap();
+    assert_eq!(state.active_workspace.as_deref(), Some("exploits"));
+    assert_eq!(state.workspaces.len(), 2);
+
+    workspace::set_active_workspace("default").unwrap();
+    let changed = changes.recv().await.unwrap();
+    assert_eq!(
+        changed.workspace.active_workspace.as_deref(),
+        Some("default")
+    );
+    assert_eq!(settings::load().unwrap(), changed);
+
+    let applied = settings::apply(serde_json::json!({
+        "editor": { "text": { "fontSize": 15 } },
+        "tray": { "enabled": true },
+    }))
+    .unwrap();
+    assert!(applied.tray.enabled && !applied.tray.show_last_script);
+    assert_eq!(changes.recv().await.unwrap(), applied);
+
+    assert!(settings::apply(serde_json::json!({ "tray": { "enabled": "yes" } })).is_err());
+    settings::apply(serde_json::json!({ "tray": { "enabled": true } })).unwrap();
+    assert!(changes.try_recv().is_err());
+    assert_eq!(settings::load().unwrap(), applied);
+}
+
+#[tokio::test]
+async fn settings_import_old_files_and_move_the_last_script_out() {
+    let env = TestEnv::start(ApiType::Hydrogen).await;
+
+    let data_dir = paths::app_data_dir();
+    std::fs::write(
+        data_dir.join("settings.json"),
+        r#"{"schema_version": 1, "tray": {"enabled": false}, "scripts": {"last_script": "print('v1')"}}"#,
+    )
+    .unwrap();
+    std::fs::write(
+        data_dir.join("executor_settings.json"),
+        r#"{"overrides": {"MacSploit": {"min_port": 5560, "max_port": 5565}}}"#,
+    )
+    .unwrap();
+    std::fs::write(data_dir.join("executor_settings.json.bak"), "{}").unwrap();
+    std::fs::write(
+        data_dir.join("updates.json"),
+        r#"{"skipped_version": "1.2.0"}"#,
+    )
+    .unwrap();
+
+    let migrated = settings::load().unwrap();
+    assert_eq!(migrated.schema_version, SCHEMA_VERSION);
+    assert!(!migrated.tray.enabled);
+    assert_eq!(
+        migrated.executor.overrides[&ApiType::MacSploit].min_port,
+        Some(5560)
+    );
+    assert_eq!(migrated.updates.skipped_version.as_deref(), Some("1.2.0"));
+    assert_eq!(scripts::read_last_script().unwrap(), "print('v1')");
+    let stored = std::fs::read_to_string(data_dir.join("settings.json")).unwrap();
+    let stored: serde_json::Value = serde_json::from_str(&stored).unwrap();
+    assert!(stored.get("scripts").is_none());
+    for legacy in [
+        "executor_settings.json",
+        "executor_settings.json.bak",
+        "updates.json",
+    ] {
+        assert!(!data_dir.join(legacy).exists(), "{} was kept", legacy);
+    }
+
+    let endpoint = &ExecutorSettings::load().endpoints()[&ApiType::MacSploit];
+    assert_eq!(endpoint.port_range(), (5560, 5565));
+    assert_eq!(updater::skipped_version().as_deref(), Some("1.2.0"));
+
+    let mut changes = settings::subscribe();
+    let options = DiscoveryOptions {
+        connect_timeout_ms: 50,
+        handshake_timeout_ms: 100,
+    };
+    env.state.set_discovery_options(options).await.unwrap();
+    let changed = changes.recv().await.unwrap();
+    assert_eq!(changed.executor.discovery, options);
+    assert_eq!(changed.executor.overrides, migrated.executor.overrides);
+    assert_eq!(env.state.connection.discovery_options().await, options);
+
+    updater::skip_version("1.3.0").unwrap();
+    let changed = changes.recv().await.unwrap();
+    assert_eq!(changed.updates.skipped_version.as_deref(), Some("1.3.0"));
+
+    assert!(env
+        .state
+        .set_discovery_options(DiscoveryOptions {
+            connect_timeout_ms: 0,
+            handshake_timeout_ms: 100,
+        })
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn settings_patches_validate_and_apply_executor_changes() {
+    let env = TestEnv::start(ApiType::Hydrogen).await;
+    let before = settings::load().unwrap();
+    let endpoint = env.state.connection.endpoint(ApiType::MacSploit);
+
+    let inverted = serde_json::json!({
+        "tray": { "enabled": false },
+        "executor": { "overrides": { "MacSploit": { "min_port": 5600, "max_port": 5500 } } },
+    });
+    assert!(env.state.apply_settings(inverted).await.is_err());
+    assert_eq!(settings::load().unwrap(), before);
+    assert_eq!(env.state.connection.endpoint(ApiType::MacSploit), endpoint);
+
+    for patch in [
+        serde_json::json!({ "schema_version": 0 }),
+        serde_json::json!({ "executor": { "discovery": { "connect_timeout_ms": 10 } } }),
+    ] {
+        assert!(settings::apply(patch).is_err());
+    }
+    assert!(env
+        .state
+        .apply_settings(serde_json::json!({ "schema_version": 0 }))
+        .await
+        .is_err());
+
+    let applied = env
+        .state
+        .apply_settings(serde_json::json!({
+            "tray": { "enabled": false },
+            "executor": { "overrides": { "MacSploit": { "min_port": 5500, "max_port": 5510 } } },
+        }))
+        .await
+        .unwrap();
+    assert!(!applied.tray.enabled);
+    assert_eq!(settings::load().unwrap(), applied);
+    assert_eq!(
+        env.state
+            .connection
+            .endpoint(ApiType::MacSploit)
+            .port_range(),
+        (5500, 5510)
+    );
+    assert_eq!(applied.schema_version, SCHEMA_VERSION);
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use comet_core::mock_executor::{MockConfig, MockExecutor};
+use comet_core::{supervisor, ApiType};
+use common::TestEnv;
+
+#[tokio::test]
+async fn failed_attempts_count_while_the_executor_is_down() {
+    let mut env = TestEnv::start(ApiType::Hydrogen).await;
+    let port = env.mock.hydrogen_ports()[0];
+
+    assert!(supervisor::check_connection(&env.state).await);
+    let status = env.state.status().await;
+    assert!(status.is_connected);
+    assert_eq!(status.port, Some(port));
+    assert!(status.metrics.latency_ms.is_some());
+
+    env.mock.shutdown();
+    for attempt in 1..=2 {
+        assert!(!supervisor::check_connection(&env.state).await);
+        let status = env.state.status().await;
+        assert!(!status.is_connected && !status.is_connecting);
+        assert_eq!(status.metrics.failed_attempts, attempt);
+        assert!(status.metrics.latency_ms.is_none());
+    }
+
+    env.mock = MockExecutor::start(MockConfig {
+        hydrogen_ports: vec![port],
+        macsploit_ports: vec![],
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    assert!(supervisor::check_connection(&env.state).await);
+    let metrics = env.state.status().await.metrics;
+    assert_eq!(metrics.failed_attempts, 0);
+    assert_eq!(metrics.reconnect_count, 1);
+    assert!(metrics.latency_ms.is_some());
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use comet_core::tabs;
+use comet_core::workspace;
+use comet_core::ApiType;
+use common::{tab, TestEnv};
+
+#[tokio::test]
+async fn workspace_tabs_rename_and_search() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+
+    let state = workspace::load_workspaces().unwrap();
+    assert_eq!(state.active_workspace.as_deref(), Some("default"));
+
+    let created = workspace::create_workspace("Game Scripts").unwrap();
+    assert_eq!(created.id, "game-scripts");
+    assert!(workspace::create_workspace("Game Scripts").is_err());
+    workspace::set_active_workspace(&created.id).unwrap();
+
+    let tabs = vec![
+        tab(
+            "a",
+            "main",
+            "local player = game.Players.LocalPlayer\nprint(player.Name)",
+        ),
+        tab("b", "utils.lua", "getgenv().speed = 16"),
+    ];
+    for tab in &tabs {
+        tabs::save_tab(&created.id, tab).unwrap();
+    }
+    tabs::save_tab_state(
+        &created.id,
+        Some("b".to_string()),
+        vec!["b".to_string(), "a".to_string()],
+        vec![tab("a", "main.lua", ""), tab("b", "utils.lua", "")],
+    )
+    .unwrap();
+
+    tabs::rename_tab(&created.id, "b", "speed hack").unwrap();
+    workspace::rename_workspace(&created.id, "Exploits").unwrap();
+
+    let loaded = tabs::load_tabs(&created.id).unwrap();
+    let mut titles = loaded
+        .iter()
+        .map(|tab| tab.title.as_str())
+        .collect::<Vec<_>>();
+    titles.sort();
+    assert_eq!(titles, ["main", "speed hack"]);
+
+    let results = tabs::search_tabs(&created.id, "GETGENV").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "speed hack");
+    assert_eq!(results[0].line_number, 1);
+    assert_eq!(results[0].column_start, 0);
+
+    let results = tabs::search_tabs(&created.id, "player").unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.title == "main"));
+
+    let state = workspace::load_workspaces().unwrap();
+    assert_eq!(state.active_workspace.as_deref(), Some("game-scripts"));
+    assert!(state
+        .workspaces
+        .iter()
+        .any(|w| w.id == "game-scripts" && w.name == "Exploits"));
+    assert!(workspace::delete_workspace(&created.id).is_err());
+}
+
+#[tokio::test]
+async fn tabs_are_stored_by_id_and_migrate_from_titles() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+
+    let tabs_dir = workspace::get_workspace_tabs_dir("default");
+    std::fs::write(tabs_dir.join("legacy.lua"), "print('legacy')").unwrap();
+    std::fs::write(tabs_dir.join("fresh.lua"), "print('fresh')").unwrap();
+    tabs::save_tab_state(
+        "default",
+        Some("old-id".to_string()),
+        vec!["old-id".to_string()],
+        vec![tab("old-id", "legacy", "")],
+    )
+    .unwrap();
+
+    let migrated = tabs::load_tabs("default").unwrap();
+    assert_eq!(migrated.len(), 2);
+    assert_eq!(migrated[0].id, "old-id");
+    assert_eq!(migrated[0].title, "legacy.lua");
+    assert_eq!(migrated[0].content, "print('legacy')");
+    assert_eq!(migrated[1].title, "fresh.lua");
+    assert!(!tabs_dir.join("legacy.lua").exists());
+    assert!(tabs_dir.join("old-id.lua").exists());
+    assert!(tabs_dir.join("old-id.meta.json").exists());
+
+    tabs::save_tab("default", &tab("copy-1", "a/b", "same")).unwrap();
+    tabs::save_tab("default", &tab("copy-2", "a?b", "same")).unwrap();
+    let loaded = tabs::load_tabs("default").unwrap();
+    let copies = loaded
+        .iter()
+        .filter(|tab| tab.content == "same")
+        .map(|tab| tab.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(copies.len(), 2);
+    assert!(copies.contains(&"a/b") && copies.contains(&"a?b"));
+
+    tabs::delete_tab("default", "copy-1").unwrap();
+    let loaded = tabs::load_tabs("default").unwrap();
+    assert_eq!(loaded.len(), 3);
+    assert!(loaded.iter().any(|tab| tab.id == "copy-2"));
+    assert!(tabs::save_tab("default", &tab("../escape", "x", "")).is_err());
+
+    // A save interrupted between its two writes leaves only the info file behind.
+    tabs::save_tab("default", &tab("torn", "Torn", "print('torn')")).unwrap();
+    std::fs::remove_file(tabs_dir.join("torn.lua")).unwrap();
+    let loaded = tabs::load_tabs("default").unwrap();
+    assert!(loaded
+        .iter()
+        .all(|tab| tab.id != "torn" && tab.title != "torn.lua"));
+    tabs::save_tab("default", &tab("torn", "Torn", "print('torn')")).unwrap();
+    let loaded = tabs::load_tabs("default").unwrap();
+    assert!(loaded
+        .iter()
+        .any(|tab| tab.id == "torn" && tab.title == "Torn"));
+}
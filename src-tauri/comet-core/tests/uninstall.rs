@@ -0,0 +1,87 @@
+mod common;
+
+use comet_core::auto_execute;
+use comet_core::brand::BrandRegistry;
+use comet_core::config::CometConfig;
+use comet_core::detector;
+use comet_core::uninstall::{self, RemovalStatus};
+use comet_core::{paths, ApiType};
+use common::TestEnv;
+
+#[tokio::test]
+async fn uninstall_previews_backs_up_and_reports_each_path() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+    let brand = BrandRegistry::from_config(CometConfig::defaults()).for_product("Comet");
+    let home = paths::home_dir().unwrap();
+    std::fs::create_dir_all(home.join("Hydrogen/workspace")).unwrap();
+    std::fs::write(home.join("Hydrogen/workspace/config.lua"), "return {}").unwrap();
+    std::fs::create_dir_all(home.join("Library/Logs/Roblox")).unwrap();
+    std::fs::write(home.join("Library/Logs/Roblox/a.log"), "0123456789").unwrap();
+    std::fs::create_dir_all(detector::roblox_macos_dir()).unwrap();
+    auto_execute::save_auto_execute_file("init.lua", "print(1)", ApiType::Hydrogen).unwrap();
+
+    let preview = uninstall::preview(&brand).unwrap();
+    let logs = preview.iter().find(|t| t.label == "Roblox logs").unwrap();
+    assert!(logs.exists && !logs.requires_admin);
+    assert_eq!(logs.size_bytes, 10);
+    let roblox = preview.iter().find(|t| t.label == "Roblox").unwrap();
+    assert!(roblox.exists && roblox.requires_admin);
+    assert!(
+        !preview
+            .iter()
+            .find(|t| t.label == "Roblox cache")
+            .unwrap()
+            .exists
+    );
+
+    let backup = uninstall::backup(&brand).unwrap();
+    let archive = flate2::read::GzDecoder::new(std::fs::File::open(&backup).unwrap());
+    let entries = tar::Archive::new(archive)
+        .entries()
+        .unwrap()
+        .map(|entry| {
+            entry
+                .unwrap()
+                .path()
+                .unwrap()
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect::<Vec<_>>();
+    assert!(entries.contains(&"Hydrogen/workspace/config.lua".to_string()));
+    assert!(entries.contains(&"Hydrogen/autoexecute/init.lua".to_string()));
+
+    let selected = vec![
+        logs.path.clone(),
+        roblox.path.clone(),
+        preview
+            .iter()
+            .find(|t| t.label == "Roblox cache")
+            .unwrap()
+            .path
+            .clone(),
+    ];
+    let mut asked = Vec::new();
+    let report = uninstall::remove(&brand, &selected, |paths| {
+        asked = paths.to_vec();
+        Err("User canceled".to_string())
+    })
+    .unwrap();
+    assert_eq!(asked, vec![std::path::PathBuf::from(&roblox.path)]);
+    let statuses = report.iter().map(|r| r.status).collect::<Vec<_>>();
+    assert_eq!(
+        statuses,
+        [
+            RemovalStatus::Removed,
+            RemovalStatus::Failed,
+            RemovalStatus::NotFound
+        ]
+    );
+    assert_eq!(report[1].error.as_deref(), Some("User canceled"));
+    assert!(!home.join("Library/Logs/Roblox").exists());
+    assert!(home.join("Hydrogen").exists());
+    assert!(backup.exists());
+
+    let outside = home.join("Documents").to_string_lossy().into_owned();
+    assert!(uninstall::remove(&brand, &[outside], |_| Ok(())).is_err());
+}
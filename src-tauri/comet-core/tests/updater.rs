@@ -0,0 +1,72 @@
+mod common;
+
+use comet_core::installer::InstallProgress;
+use comet_core::updater;
+use comet_core::{paths, ApiType};
+use common::{serve_file, TestEnv};
+
+#[tokio::test]
+async fn updates_are_compared_skipped_and_verified() {
+    let _env = TestEnv::start(ApiType::Hydrogen).await;
+    let client = reqwest::Client::new();
+    let artifact = serve_file(b"echo installed\n").await;
+    let manifest = paths::app_data_dir().join("latest.json");
+    let source = manifest.to_string_lossy().into_owned();
+    let publish = |version: &str, sha256: &str| {
+        let release = serde_json::json!({
+            "version": version,
+            "notes": "Faster tabs",
+            "url": artifact,
+            "sha256": sha256,
+        });
+        std::fs::write(&manifest, release.to_string()).unwrap();
+    };
+    let sha256 = "99e966666677428551eaa369f4266e334592025a7e6a54aa87d3933c7f0e3a80";
+
+    publish("1.2.0", sha256);
+    assert_eq!(
+        updater::check(&client, &source, "1.2.0").await.unwrap(),
+        None
+    );
+    let update = updater::check(&client, &source, "1.1.9")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.version, "1.2.0");
+    assert_eq!(update.notes, "Faster tabs");
+    assert!(updater::check(&client, &source, "not a version")
+        .await
+        .is_err());
+
+    updater::skip_version("1.2.0").unwrap();
+    assert_eq!(
+        updater::check(&client, &source, "1.1.9").await.unwrap(),
+        None
+    );
+    publish("1.10.0", sha256);
+    let update = updater::check(&client, &source, "1.1.9")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.version, "1.10.0");
+
+    assert!(updater::download(&client, &source, "1.2.0", |_| {})
+        .await
+        .unwrap_err()
+        .contains("no longer the latest"));
+    let path = updater::download(&client, &source, "1.10.0", |_| {})
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "echo installed\n");
+
+    publish("1.11.0", &"0".repeat(64));
+    let mut progress = Vec::new();
+    let error = updater::download(&client, &source, "1.11.0", |step| progress.push(step))
+        .await
+        .unwrap_err();
+    assert!(error.starts_with("Update checksum mismatch"));
+    assert!(progress.contains(&InstallProgress::Verifying));
+    assert!(!paths::app_data_dir()
+        .join("updates/1.11.0/install")
+        .exists());
+}
@@ -1,9 +1,11 @@
 use base64::{engine::general_purpose, Engine as _};
+use comet_core::brand::Brand;
+use comet_core::config::ConfigStore;
 use curl::easy::Easy;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::process::Command;
-use tauri::async_runtime;
+use tauri::{async_runtime, State};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyValidationRequest {
@@ -57,13 +59,8 @@ fn get_mac_uuid() -> Result<String, String> {
     Err("Could not find Hardware UUID".to_string())
 }
 
-fn read_key_file() -> Result<String, String> {
-    let home = dirs::home_dir().ok_or("Could not find home directory")?;
-    let hydrogen_dir = home
-        .join("Library")
-        .join("Application Support")
-        .join("Hydrogen");
-    let key_path = hydrogen_dir.join("key.txt");
+fn read_key_file(brand: &Brand) -> Result<String, String> {
+    let key_path = brand.key_file()?;
 
     if !key_path.exists() {
         let path_str = key_path.to_string_lossy();
@@ -117,13 +114,14 @@ fn calculate_days_remaining(expires_at: u64, current_time: u64) -> i64 {
 }
 
 #[tauri::command]
-pub async fn validate_key() -> Result<KeyStatus, String> {
+pub async fn validate_key(config: State<'_, ConfigStore>) -> Result<KeyStatus, String> {
     let current_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_err(|e| format!("Failed to get current time: {}", e))?
         .as_secs();
 
-    let (key, mac_uuid) = match (read_key_file(), get_mac_uuid()) {
+    let brand = config.brand();
+    let (key, mac_uuid) = match (read_key_file(&brand), get_mac_uuid()) {
         (Ok(key), Ok(uuid)) => (key, uuid),
         (Err(e), _) => {
             return Ok(KeyStatus {
@@ -149,7 +147,24 @@ pub async fn validate_key() -> Result<KeyStatus, String> {
         }
     };
 
+    let Some(validation_url) = brand.key_validation_url().map(str::to_string) else {
+        return Ok(KeyStatus {
+            success: false,
+            key_found: true,
+            valid: false,
+            expires_at: None,
+            current_time,
+            days_remaining: None,
+            error: Some(format!("{} keys can't be validated", brand.executor_name())),
+        });
+    };
+
     let result = match async_runtime::spawn_blocking(move || {
+        let host = reqwest::Url::parse(&validation_url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .ok_or_else(|| format!("Invalid key validation URL: {}", validation_url))?;
+
         let request_body = KeyValidationRequest {
             key: key.clone(),
             mac_uuid: mac_uuid.clone(),
@@ -161,7 +176,7 @@ pub async fn validate_key() -> Result<KeyStatus, String> {
         let mut response_buffer = Vec::new();
         let mut easy = Easy::new();
 
-        easy.url("https://www.hydrogen.lat/api/validate-key")
+        easy.url(&validation_url)
             .map_err(|e| format!("Failed to set URL: {}", e))?;
         easy.timeout(std::time::Duration::from_secs(15))
             .map_err(|e| format!("Failed to set timeout: {}", e))?;
@@ -177,7 +192,7 @@ pub async fn validate_key() -> Result<KeyStatus, String> {
             .append("Content-Type: application/json")
             .map_err(|e| format!("Failed to add Content-Type header: {}", e))?;
         headers
-            .append("Host: www.hydrogen.lat")
+            .append(&format!("Host: {}", host))
             .map_err(|e| format!("Failed to add Host header: {}", e))?;
         headers
             .append("User-Agent: curl/8.12.1")
@@ -0,0 +1,450 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use tauri::{Manager, State, SystemTray, SystemTrayMenu, Window, WindowEvent};
+use tokio::sync::broadcast::error::RecvError;
+
+use comet_core::backend::{EndpointConfig, ExecuteError};
+use comet_core::brand::Brand;
+use comet_core::config::{self, ConfigStore, LoadedConfig};
+use comet_core::connection::{ExecutionResult, ExecutionTarget, ExecutorInstance, InstanceResult};
+use comet_core::discovery::DiscoveryOptions;
+use comet_core::executor_settings::ExecutorSettings;
+use comet_core::process::{self, ProcessEvent};
+use comet_core::{detector, ipc, paths, scripts, supervisor, ApiType, AppState, ConnectionStatus};
+
+#[derive(Debug)]
+struct WindowState {
+    is_focused: Arc<Mutex<bool>>,
+}
+
+impl WindowState {
+    fn new() -> Self {
+        Self {
+            is_focused: Arc::new(Mutex::new(false)),
+        }
+    }
+}
+
+#[tauri::command]
+async fn get_connection_status(state: State<'_, AppState>) -> Result<ConnectionStatus, String> {
+    Ok(state.status().await)
+}
+
+#[tauri::command]
+async fn get_api_type(state: State<'_, AppState>) -> Result<ApiType, String> {
+    Ok(state.connection.api_type().await)
+}
+
+#[tauri::command]
+async fn set_api_type(
+    api_type: ApiType,
+    state: State<'_, AppState>,
+) -> Result<ConnectionStatus, String> {
+    Ok(state.set_api_type(api_type).await)
+}
+
+#[tauri::command]
+async fn send_script(
+    script: String,
+    state: State<'_, AppState>,
+) -> Result<ExecutionResult, ExecuteError> {
+    state.execute(&script).await
+}
+
+#[tauri::command]
+async fn list_executor_instances(
+    state: State<'_, AppState>,
+) -> Result<Vec<ExecutorInstance>, String> {
+    Ok(state.connection.list_instances().await)
+}
+
+#[tauri::command]
+async fn send_script_to(
+    script: String,
+    target: ExecutionTarget,
+    state: State<'_, AppState>,
+) -> Result<Vec<InstanceResult>, String> {
+    Ok(state.send_to(&script, target).await)
+}
+
+#[tauri::command]
+async fn get_discovery_options(state: State<'_, AppState>) -> Result<DiscoveryOptions, String> {
+    Ok(state.connection.discovery_options().await)
+}
+
+#[tauri::command]
+async fn set_discovery_options(
+    options: DiscoveryOptions,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    state.set_discovery_options(options).await
+}
+
+#[tauri::command]
+async fn get_executor_settings() -> Result<ExecutorSettings, String> {
+    Ok(ExecutorSettings::load())
+}
+
+#[tauri::command]
+async fn get_executor_endpoints(
+    state: State<'_, AppState>,
+) -> Result<HashMap<ApiType, EndpointConfig>, String> {
+    Ok(state.endpoints())
+}
+
+#[tauri::command]
+async fn update_executor_settings(
+    settings: ExecutorSettings,
+    state: State<'_, AppState>,
+) -> Result<HashMap<ApiType, EndpointConfig>, String> {
+    state.update_executor_settings(settings).await
+}
+
+#[tauri::command]
+async fn change_setting(
+    key: String,
+    value: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    Ok(state.change_setting(&key, &value).await)
+}
+
+#[tauri::command]
+async fn refresh_connection(state: State<'_, AppState>) -> Result<ConnectionStatus, String> {
+    Ok(state.refresh_connection().await)
+}
+
+#[tauri::command]
+async fn increment_port(state: State<'_, AppState>) -> Result<ConnectionStatus, String> {
+    Ok(state.increment_port().await)
+}
+
+#[tauri::command]
+fn close_window(window: tauri::Window) {
+    window.close().unwrap();
+}
+
+#[tauri::command]
+fn minimize_window(window: tauri::Window) {
+    window.minimize().unwrap();
+}
+
+#[tauri::command]
+fn toggle_maximize_window(window: tauri::Window) {
+    if window.is_maximized().unwrap() {
+        window.unmaximize().unwrap();
+    } else {
+        window.maximize().unwrap();
+    }
+}
+
+#[tauri::command]
+fn set_window_always_on_top(window: tauri::Window, always_on_top: bool) {
+    window.set_always_on_top(always_on_top).unwrap();
+}
+
+#[tauri::command]
+fn hide_window(window: Window) {
+    window.hide().unwrap();
+}
+
+mod auto_execute;
+mod execution_history;
+mod executor;
+mod key;
+mod login_items;
+mod roblox_logs;
+mod rscripts;
+mod settings;
+mod tabs;
+mod tray;
+mod uninstall;
+mod updater;
+mod workspace;
+
+#[tauri::command]
+async fn open_roblox() -> Result<(), String> {
+    Command::new("open")
+        .arg("-a")
+        .arg("Roblox")
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub fn open_directory(path: PathBuf) -> Result<(), String> {
+    if !path.exists() {
+        fs::create_dir_all(&path).map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+
+    Command::new("open")
+        .arg(path)
+        .spawn()
+        .map_err(|e| format!("Failed to open directory: {}", e))?;
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn open_comet_folder() -> Result<(), String> {
+    open_directory(paths::app_data_dir())
+}
+
+#[tauri::command]
+async fn open_executor_folder(config: State<'_, ConfigStore>) -> Result<(), String> {
+    open_directory(config.brand().data_dir()?)
+}
+
+#[tauri::command]
+async fn execute_last_script(state: State<'_, AppState>) -> Result<ExecutionResult, String> {
+    scripts::run_last_script(&state).await
+}
+
+#[tauri::command]
+async fn save_last_script(script: String) -> Result<(), String> {
+    scripts::save_last_script(&script)
+}
+
+#[tauri::command]
+async fn is_login_item_enabled() -> Result<bool, String> {
+    login_items::is_login_item_enabled()
+}
+
+#[tauri::command]
+async fn toggle_login_item(enabled: bool) -> Result<(), String> {
+    login_items::toggle_login_item(enabled)
+}
+
+/// The branding of this build: product and executor names, install URL and paths.
+#[tauri::command]
+fn get_brand(config: State<'_, ConfigStore>) -> Brand {
+    config.brand()
+}
+
+/// The merged config and any layers that were skipped as invalid.
+#[tauri::command]
+fn get_config(config: State<'_, ConfigStore>) -> LoadedConfig {
+    config.current()
+}
+
+pub fn run() {
+    let app_state = AppState::new();
+    let state_clone = app_state.clone();
+    let mut executor_output = app_state.connection.subscribe_output();
+    let mut status_updates = app_state.subscribe_status();
+    let mut process_events = app_state.subscribe_processes();
+    let window_state = WindowState::new();
+
+    tauri::Builder::default()
+        .manage(app_state)
+        .manage(window_state)
+        .system_tray(SystemTray::new().with_menu(SystemTrayMenu::new()))
+        .on_system_tray_event(tray::handle_tray_event)
+        .on_window_event(|event| {
+            if let WindowEvent::CloseRequested { api, .. } = event.event() {
+                event.window().hide().unwrap();
+                api.prevent_close();
+            }
+
+            if let WindowEvent::Focused(focused) = event.event() {
+                let state: State<WindowState> = event.window().state();
+                *state.is_focused.lock().unwrap() = *focused;
+            }
+        })
+        .setup(|app| {
+            let config_store = ConfigStore::load(Some(app.package_info().name.clone()));
+            for error in config_store.current().errors {
+                eprintln!("Ignored config layer: {}", error);
+            }
+            app.manage(config_store.clone());
+            let config_handle = app.app_handle();
+            match config::watch(config_store, move |loaded| {
+                config_handle
+                    .emit_all("config-changed", loaded)
+                    .unwrap_or_default();
+            }) {
+                Ok(watcher) => {
+                    app.manage(watcher);
+                }
+                Err(e) => eprintln!("Not watching the config: {}", e),
+            }
+            let window = app.get_window("main").unwrap();
+
+            let app_handle = app.app_handle();
+            let tray_config = tray::get_tray_config().unwrap_or_default();
+
+            if tray_config.enabled {
+                let _ = tray::update_tray_menu(app_handle);
+            } else {
+                app.tray_handle().set_menu(SystemTrayMenu::new()).unwrap();
+            }
+
+            let output_handle = app.app_handle();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    match executor_output.recv().await {
+                        Ok(output) => {
+                            output_handle
+                                .emit_all("executor-output", output)
+                                .unwrap_or_default();
+                        }
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            });
+
+            if let Err(e) = roblox_logs::start(window) {
+                eprintln!("Error watching log file: {}", e);
+            }
+
+            let status_handle = app.app_handle();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    match status_updates.recv().await {
+                        Ok(status) => {
+                            tray::update_connection_item(&status_handle, &status);
+                            status_handle
+                                .emit_all("connection-update", status)
+                                .unwrap_or_default();
+                        }
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            });
+
+            let detection_handle = app.app_handle();
+            match detector::watch(move |report| {
+                detection_handle
+                    .emit_all("detection-changed", report)
+                    .unwrap_or_default();
+            }) {
+                Ok(watcher) => {
+                    app.manage(watcher);
+                }
+                Err(e) => eprintln!("Not watching for executor installs: {}", e),
+            }
+
+            let control_state = state_clone.clone();
+            tauri::async_runtime::spawn(async move {
+                match ipc::bind().await {
+                    Ok(socket) => ipc::serve(socket, control_state, None).await,
+                    Err(e) => eprintln!("Control API unavailable: {}", e),
+                }
+            });
+
+            let process_handle = app.app_handle();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    match process_events.recv().await {
+                        Ok(ProcessEvent::Launched { process }) => {
+                            process_handle
+                                .emit_all("roblox-launched", process)
+                                .unwrap_or_default();
+                        }
+                        Ok(ProcessEvent::Exited { pid }) => {
+                            process_handle
+                                .emit_all("roblox-exited", pid)
+                                .unwrap_or_default();
+                        }
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            });
+
+            tauri::async_runtime::spawn(settings::forward_changes(app.app_handle()));
+            tauri::async_runtime::spawn(updater::watch_for_updates(app.app_handle()));
+            tauri::async_runtime::spawn(process::run(state_clone.clone()));
+            tauri::async_runtime::spawn(supervisor::run(state_clone));
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            get_connection_status,
+            get_api_type,
+            set_api_type,
+            send_script,
+            list_executor_instances,
+            send_script_to,
+            get_discovery_options,
+            set_discovery_options,
+            get_executor_settings,
+            get_executor_endpoints,
+            update_executor_settings,
+            change_setting,
+            refresh_connection,
+            increment_port,
+            tabs::save_tab,
+            tabs::delete_tab,
+            tabs::save_tab_state,
+            tabs::load_tabs,
+            tabs::get_tab_state,
+            tabs::rename_tab,
+            close_window,
+            minimize_window,
+            toggle_maximize_window,
+            set_window_always_on_top,
+            execute_last_script,
+            save_last_script,
+            auto_execute::get_auto_execute_files,
+            auto_execute::save_auto_execute_file,
+            auto_execute::delete_auto_execute_file,
+            auto_execute::rename_auto_execute_file,
+            auto_execute::open_auto_execute_directory,
+            auto_execute::is_auto_execute_enabled,
+            auto_execute::toggle_auto_execute,
+            open_roblox,
+            roblox_logs::start_log_watcher,
+            roblox_logs::stop_log_watcher,
+            executor::check_executor_installation,
+            executor::get_detection_report,
+            executor::get_install_log,
+            executor::install_app,
+            workspace::load_workspaces,
+            workspace::create_workspace,
+            workspace::delete_workspace,
+            workspace::set_active_workspace,
+            workspace::rename_workspace,
+            open_executor_folder,
+            open_comet_folder,
+            hide_window,
+            execution_history::load_execution_history,
+            execution_history::save_execution_record,
+            execution_history::clear_execution_history,
+            tabs::export_tab,
+            tabs::search_tabs,
+            tabs::list_tab_revisions,
+            tabs::diff_tab_revisions,
+            tabs::restore_tab_revision,
+            rscripts::search_rscripts,
+            rscripts::get_rscript_content,
+            tray::get_tray_config,
+            tray::save_tray_config,
+            tray::update_tray_menu,
+            tray::add_custom_tray_script,
+            tray::update_custom_tray_script,
+            tray::remove_custom_tray_script,
+            tray::reorder_custom_tray_scripts,
+            is_login_item_enabled,
+            toggle_login_item,
+            get_brand,
+            get_config,
+            settings::get_settings,
+            settings::set_settings,
+            uninstall::preview_uninstall,
+            uninstall::backup_before_uninstall,
+            uninstall::uninstall_app,
+            uninstall::quit_app,
+            updater::check_for_updates,
+            updater::download_update,
+            updater::skip_update,
+            key::validate_key,
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
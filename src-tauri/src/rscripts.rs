@@ -1,7 +1,6 @@
 use reqwest::Client;
 use serde::Deserialize;
 use serde_json::Value;
-use tauri;
 
 #[derive(Debug, Deserialize)]
 pub struct RScriptSearchParams {
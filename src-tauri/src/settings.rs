@@ -0,0 +1,34 @@
+use serde_json::Value;
+use tauri::{Manager, State};
+use tokio::sync::broadcast::error::RecvError;
+
+use comet_core::settings::{self, Settings};
+use comet_core::AppState;
+
+/// Emits `settings-changed` to every window whenever the settings are saved, so windows stay
+/// in sync no matter which one (or which backend module) changed them.
+pub async fn forward_changes(app_handle: tauri::AppHandle) {
+    let mut changes = settings::subscribe();
+    loop {
+        match changes.recv().await {
+            Ok(settings) => {
+                app_handle
+                    .emit_all("settings-changed", settings)
+                    .unwrap_or_default();
+            }
+            Err(RecvError::Lagged(_)) => continue,
+            Err(RecvError::Closed) => break,
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_settings() -> Result<Settings, String> {
+    settings::load()
+}
+
+/// Merges `patch`, any subset of the settings, into the stored settings.
+#[tauri::command]
+pub async fn set_settings(state: State<'_, AppState>, patch: Value) -> Result<Settings, String> {
+    state.apply_settings(patch).await
+}
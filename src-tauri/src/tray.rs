@@ -1,33 +1,41 @@
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Mutex;
 use tauri::{
     AppHandle, CustomMenuItem, Manager, SystemTrayEvent, SystemTrayMenu, SystemTrayMenuItem,
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CustomTrayScript {
-    pub id: String,
-    pub name: String,
-    pub content: String,
-    pub order: usize,
-}
+use comet_core::scripts::{
+    execute_script_by_key, fetch_script_configs, format_script_name, run_last_script, ScriptConfig,
+};
+use comet_core::settings::{self, CustomTrayScript, TrayConfig};
+use comet_core::{AppState, ConnectionStatus};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TrayConfig {
-    pub enabled: bool,
-    pub show_scripts: bool,
-    pub show_last_script: bool,
-    pub custom_scripts: Vec<CustomTrayScript>,
-}
+const CONNECTION_ITEM_ID: &str = "connection_status";
+
+static CONNECTION_LABEL: Mutex<String> = Mutex::new(String::new());
 
-impl Default for TrayConfig {
-    fn default() -> Self {
-        Self {
-            enabled: true,
-            show_scripts: true,
-            show_last_script: true,
-            custom_scripts: Vec::new(),
+fn connection_label(status: &ConnectionStatus) -> String {
+    if status.is_connected {
+        match (status.port, status.metrics.latency_ms) {
+            (Some(port), Some(latency)) => format!("Connected on {} ({} ms)", port, latency),
+            (Some(port), None) => format!("Connected on {}", port),
+            _ => "Connected".to_string(),
         }
+    } else if status.is_connecting {
+        "Connecting...".to_string()
+    } else if status.roblox_processes.is_empty() {
+        "Roblox not running".to_string()
+    } else {
+        "Executor not injected".to_string()
+    }
+}
+
+pub fn update_connection_item(app_handle: &AppHandle, status: &ConnectionStatus) {
+    let label = connection_label(status);
+    *CONNECTION_LABEL.lock().unwrap() = label.clone();
+
+    if let Some(item) = app_handle.tray_handle().try_get_item(CONNECTION_ITEM_ID) {
+        let _ = item.set_title(label);
     }
 }
 
@@ -37,18 +45,28 @@ fn create_header(id: &str, title: &str) -> CustomMenuItem {
 }
 
 pub fn create_tray_menu(
-    scripts: Option<HashMap<String, super::ScriptConfig>>,
+    scripts: Option<HashMap<String, ScriptConfig>>,
     config: &TrayConfig,
 ) -> SystemTrayMenu {
     if !config.enabled {
         return SystemTrayMenu::new();
     }
 
+    let connection_label = match CONNECTION_LABEL.lock().unwrap().as_str() {
+        "" => "Disconnected".to_string(),
+        label => label.to_string(),
+    };
+    let header_connection = create_header("connection", "Connection");
+    let connection = CustomMenuItem::new(CONNECTION_ITEM_ID, connection_label).disabled();
+
     let header_window = create_header("window", "Window Controls");
     let open = CustomMenuItem::new("open".to_string(), "Show Window");
     let hide = CustomMenuItem::new("hide".to_string(), "Hide Window");
 
     let mut tray_menu = SystemTrayMenu::new()
+        .add_item(header_connection)
+        .add_item(connection)
+        .add_native_item(SystemTrayMenuItem::Separator)
         .add_item(header_window)
         .add_item(open)
         .add_item(hide)
@@ -81,7 +99,7 @@ pub fn create_tray_menu(
                 for (key, config) in scripts {
                     let display_name = config
                         .display_name
-                        .unwrap_or_else(|| super::format_script_name(&key));
+                        .unwrap_or_else(|| format_script_name(&key));
                     let menu_item = CustomMenuItem::new(
                         format!("execute_{}", key),
                         format!("Execute {}", display_name),
@@ -114,96 +132,73 @@ pub fn create_tray_menu(
 }
 
 pub fn handle_tray_event(app: &AppHandle, event: SystemTrayEvent) {
-    match event {
-        SystemTrayEvent::MenuItemClick { id, .. } => {
-            if id.starts_with("header_") {
-                return;
-            }
+    if let SystemTrayEvent::MenuItemClick { id, .. } = event {
+        if id.starts_with("header_") {
+            return;
+        }
 
-            match id.as_str() {
-                "open" => {
-                    if let Some(window) = app.get_window("main") {
-                        window.show().unwrap();
-                        window.set_focus().unwrap();
-                    }
-                }
-                "hide" => {
-                    if let Some(window) = app.get_window("main") {
-                        window.hide().unwrap();
-                    }
+        match id.as_str() {
+            "open" => {
+                if let Some(window) = app.get_window("main") {
+                    window.show().unwrap();
+                    window.set_focus().unwrap();
                 }
-                id if id.starts_with("execute_") => {
-                    let script_key = id.strip_prefix("execute_").unwrap().to_string();
-                    tauri::async_runtime::spawn(async move {
-                        let _ = super::execute_script_by_key(&script_key).await;
-                    });
+            }
+            "hide" => {
+                if let Some(window) = app.get_window("main") {
+                    window.hide().unwrap();
                 }
-                id if id.starts_with("custom_") => {
-                    let script_id = id.strip_prefix("custom_").unwrap().to_string();
-
-                    if let Ok(config) = get_tray_config(app.app_handle()) {
-                        if let Some(script) =
-                            config.custom_scripts.iter().find(|s| s.id == script_id)
-                        {
-                            let content = script.content.clone();
-                            tauri::async_runtime::spawn(async move {
-                                let _ = super::execute_script(content).await;
-                            });
-                        }
+            }
+            id if id.starts_with("execute_") => {
+                let script_key = id.strip_prefix("execute_").unwrap().to_string();
+                let state = app.state::<AppState>().inner().clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = execute_script_by_key(&state, &script_key).await {
+                        eprintln!("Failed to execute {}: {}", script_key, e);
+                    }
+                });
+            }
+            id if id.starts_with("custom_") => {
+                let script_id = id.strip_prefix("custom_").unwrap().to_string();
+
+                if let Ok(config) = get_tray_config() {
+                    if let Some(script) = config.custom_scripts.iter().find(|s| s.id == script_id) {
+                        let content = script.content.clone();
+                        let state = app.state::<AppState>().inner().clone();
+                        tauri::async_runtime::spawn(async move {
+                            if let Err(e) = state.execute(&content).await {
+                                eprintln!("Failed to execute custom tray script: {}", e);
+                            }
+                        });
                     }
                 }
-                "last_script" => {
-                    tauri::async_runtime::spawn(async {
-                        let _ = super::execute_last_script().await;
-                    });
-                }
-                "quit" => {
-                    app.exit(0);
-                }
-                _ => {}
             }
+            "last_script" => {
+                let state = app.state::<AppState>().inner().clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = run_last_script(&state).await {
+                        eprintln!("Failed to execute last script: {}", e);
+                    }
+                });
+            }
+            "quit" => {
+                app.exit(0);
+            }
+            _ => {}
         }
-        _ => {}
     }
 }
 
 #[tauri::command]
-pub fn get_tray_config(app_handle: tauri::AppHandle) -> Result<TrayConfig, String> {
-    let path = app_handle
-        .path_resolver()
-        .app_data_dir()
-        .ok_or_else(|| "Failed to get app data directory".to_string())?
-        .join("tray_config.json");
-
-    if !path.exists() {
-        return Ok(TrayConfig::default());
-    }
-
-    let content =
-        std::fs::read_to_string(path).map_err(|e| format!("Failed to read tray config: {}", e))?;
-
-    serde_json::from_str(&content).map_err(|e| format!("Failed to parse tray config: {}", e))
+pub fn get_tray_config() -> Result<TrayConfig, String> {
+    Ok(settings::load()?.tray)
 }
 
 #[tauri::command]
 pub fn save_tray_config(app_handle: tauri::AppHandle, config: TrayConfig) -> Result<(), String> {
-    let app_data_dir = app_handle
-        .path_resolver()
-        .app_data_dir()
-        .ok_or_else(|| "Failed to get app data directory".to_string())?;
-
-    if !app_data_dir.exists() {
-        std::fs::create_dir_all(&app_data_dir)
-            .map_err(|e| format!("Failed to create app data directory: {}", e))?;
-    }
-
-    let path = app_data_dir.join("tray_config.json");
-    let content = serde_json::to_string_pretty(&config)
-        .map_err(|e| format!("Failed to serialize tray config: {}", e))?;
-
-    std::fs::write(path, content).map_err(|e| format!("Failed to write tray config: {}", e))?;
+    settings::update(|settings| settings.tray = config.clone())?;
 
-    let scripts = match tauri::async_runtime::block_on(super::fetch_script_configs()) {
+    let scripts = match tauri::async_runtime::block_on(fetch_script_configs()) {
         Ok(configs) => Some(configs.scripts),
         Err(_) => None,
     };
@@ -223,9 +218,9 @@ pub fn save_tray_config(app_handle: tauri::AppHandle, config: TrayConfig) -> Res
 
 #[tauri::command]
 pub fn update_tray_menu(app_handle: tauri::AppHandle) -> Result<(), String> {
-    let config = get_tray_config(app_handle.clone())?;
+    let config = get_tray_config()?;
 
-    let scripts = match tauri::async_runtime::block_on(super::fetch_script_configs()) {
+    let scripts = match tauri::async_runtime::block_on(fetch_script_configs()) {
         Ok(configs) => Some(configs.scripts),
         Err(_) => None,
     };
@@ -249,7 +244,7 @@ pub fn add_custom_tray_script(
     name: String,
     content: String,
 ) -> Result<(), String> {
-    let mut config = get_tray_config(app_handle.clone())?;
+    let mut config = get_tray_config()?;
 
     let id = format!("{}", uuid::Uuid::new_v4().as_simple());
 
@@ -279,7 +274,7 @@ pub fn update_custom_tray_script(
     content: Option<String>,
     order: Option<usize>,
 ) -> Result<(), String> {
-    let mut config = get_tray_config(app_handle.clone())?;
+    let mut config = get_tray_config()?;
 
     if let Some(script) = config.custom_scripts.iter_mut().find(|s| s.id == id) {
         if let Some(name) = name {
@@ -300,7 +295,7 @@ pub fn update_custom_tray_script(
 
 #[tauri::command]
 pub fn remove_custom_tray_script(app_handle: tauri::AppHandle, id: String) -> Result<(), String> {
-    let mut config = get_tray_config(app_handle.clone())?;
+    let mut config = get_tray_config()?;
 
     let initial_len = config.custom_scripts.len();
     config.custom_scripts.retain(|s| s.id != id);
@@ -317,7 +312,7 @@ pub fn reorder_custom_tray_scripts(
     app_handle: tauri::AppHandle,
     ids: Vec<String>,
 ) -> Result<(), String> {
-    let mut config = get_tray_config(app_handle.clone())?;
+    let mut config = get_tray_config()?;
 
     for id in &ids {
         if !config.custom_scripts.iter().any(|s| &s.id == id) {
@@ -0,0 +1,63 @@
+use std::process::Command;
+use std::time::Duration;
+use tauri::{Manager, State};
+
+use comet_core::config::ConfigStore;
+use comet_core::updater::{self, UpdateInfo};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Checks the brand's release manifest now and every few hours, emitting `update-available`
+/// when a newer, unskipped version is out. Brands without a manifest are never checked.
+pub async fn watch_for_updates(app_handle: tauri::AppHandle) {
+    loop {
+        match check_for_updates(app_handle.clone(), app_handle.state()).await {
+            Ok(Some(update)) => {
+                app_handle
+                    .emit_all("update-available", update)
+                    .unwrap_or_default();
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("Failed to check for updates: {}", e),
+        }
+        tokio::time::sleep(CHECK_INTERVAL).await;
+    }
+}
+
+#[tauri::command]
+pub async fn check_for_updates(
+    app_handle: tauri::AppHandle,
+    config: State<'_, ConfigStore>,
+) -> Result<Option<UpdateInfo>, String> {
+    let Some(manifest) = config.brand().update_manifest().map(str::to_string) else {
+        return Ok(None);
+    };
+    let current_version = app_handle.package_info().version.to_string();
+    updater::check(&reqwest::Client::new(), &manifest, &current_version).await
+}
+
+/// Downloads and verifies release `version`, then opens it so the user can install it.
+#[tauri::command]
+pub async fn download_update(window: tauri::Window, version: String) -> Result<String, String> {
+    let manifest = window
+        .state::<ConfigStore>()
+        .brand()
+        .config
+        .update_manifest
+        .ok_or("No updates are published for this build")?;
+    let path = updater::download(&reqwest::Client::new(), &manifest, &version, |progress| {
+        let _ = window.emit("update-progress", progress);
+    })
+    .await?;
+
+    Command::new("open")
+        .arg(&path)
+        .spawn()
+        .map_err(|e| format!("Failed to open update: {}", e))?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+#[tauri::command]
+pub async fn skip_update(version: String) -> Result<(), String> {
+    updater::skip_version(&version)
+}
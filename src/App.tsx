@@ -1,4 +1,5 @@
 import { type FC, useEffect, useState } from "react";
+import { toast } from "react-hot-toast";
 import { Library } from "./components/library";
 import { Settings } from "./components/settings/settings";
 import { Sidebar } from "./components/sidebar";
@@ -18,13 +19,19 @@ import { listen } from "@tauri-apps/api/event";
 import { AutoExecute } from "./components/autoExecute/autoExecute";
 import { CometOffline } from "./components/ui/cometOffline";
 import { ExecutorNotFound } from "./components/ui/executorNotFound";
+import { UpdateAvailable } from "./components/ui/updateAvailable";
+import { ConnectionProvider } from "./contexts/connection/connectionContext";
 import { ConsoleProvider } from "./contexts/console/consoleContext";
 import { ExecutionHistoryProvider } from "./contexts/execution/executionHistoryContext";
 import { SidebarProvider } from "./contexts/sidebar/sidebarContext";
 import { WorkspaceProvider } from "./contexts/workspace/workspaceContext";
 import { useKeybinds } from "./hooks/core/useKeybinds";
 import { useConsole } from "./hooks/ui/useConsole";
-import { checkExecutorInstallation } from "./services/features/executorService";
+import {
+    checkExecutorInstallation,
+    onDetectionChanged,
+} from "./services/features/executorService";
+import { onConfigChanged } from "./services/system/configService";
 import type { StatusInfo } from "./types/system/status";
 
 const AppContent: FC = () => {
@@ -113,8 +120,28 @@ const App: FC = () => {
             },
         );
 
+        // Installing or removing an executor while Comet runs switches between
+        // the editor and the setup screen without a restart.
+        const unsubscribeDetection = onDetectionChanged((report) => {
+            checkExecutor();
+            if (report.conflict) {
+                toast(
+                    `Several executors are installed, using ${report.recommended}`,
+                    { id: "executor-conflict-toast" },
+                );
+            }
+        });
+
+        const unsubscribeConfig = onConfigChanged(({ errors }) => {
+            for (const error of errors) {
+                toast.error(`Ignored invalid config: ${error}`);
+            }
+        });
+
         return () => {
             unsubscribe.then((fn) => fn());
+            unsubscribeDetection.then((fn) => fn());
+            unsubscribeConfig.then((fn) => fn());
         };
     }, []);
 
@@ -127,24 +154,27 @@ const App: FC = () => {
     }
 
     return (
-        <ExecuteProvider>
-            <SettingsProvider>
-                <WorkspaceProvider>
-                    <ExecutionHistoryProvider>
-                        <EditorProvider>
-                            <ConsoleProvider>
-                                <SidebarProvider>
-                                    <KeybindsProvider>
-                                        <AppContent />
-                                        <Toaster />
-                                    </KeybindsProvider>
-                                </SidebarProvider>
-                            </ConsoleProvider>
-                        </EditorProvider>
-                    </ExecutionHistoryProvider>
-                </WorkspaceProvider>
-            </SettingsProvider>
-        </ExecuteProvider>
+        <ConnectionProvider>
+            <ExecuteProvider>
+                <SettingsProvider>
+                    <WorkspaceProvider>
+                        <ExecutionHistoryProvider>
+                            <EditorProvider>
+                                <ConsoleProvider>
+                                    <SidebarProvider>
+                                        <KeybindsProvider>
+                                            <AppContent />
+                                            <UpdateAvailable />
+                                            <Toaster />
+                                        </KeybindsProvider>
+                                    </SidebarProvider>
+                                </ConsoleProvider>
+                            </EditorProvider>
+                        </ExecutionHistoryProvider>
+                    </WorkspaceProvider>
+                </SettingsProvider>
+            </ExecuteProvider>
+        </ConnectionProvider>
     );
 };
 
@@ -123,6 +123,14 @@ const ConsoleLog = memo<{ log: LogLine; isResizing: boolean }>(
                 {new Date(log.timestamp).toLocaleTimeString()}
             </span>
             <span className="select-none font-medium">[{log.level}]</span>{" "}
+            {log.source?.scriptPreview && (
+                <span
+                    className="mr-2 select-none text-ctp-subtext0"
+                    title={`Port ${log.source.port}`}
+                >
+                    {log.source.scriptPreview} ›
+                </span>
+            )}
             <span
                 className={`${isResizing ? "select-none" : "select-text"} break-all`}
             >
@@ -22,12 +22,12 @@ import {
 } from "../../../services/core/windowService";
 import { validateKey } from "../../../services/system/keyService";
 import { toggleLoginItem } from "../../../services/system/loginItemsService";
-import { uninstallApp } from "../../../services/system/uninstallService";
 import type { KeyStatus } from "../../../types/system/key";
 import { Checkbox } from "../../ui/input/checkbox";
 import { Modal } from "../../ui/modal";
 import { SettingGroup } from "../settingGroup";
 import { TechStackItem } from "../techStackItem";
+import { UninstallModal } from "../uninstallModal";
 
 export const ApplicationSection: FC = () => {
     const { settings, updateSettings } = useSettings();
@@ -456,44 +456,10 @@ export const ApplicationSection: FC = () => {
                 confirmVariant="destructive"
             />
 
-            <Modal
+            <UninstallModal
                 isOpen={showUninstallConfirm}
                 onClose={() => setShowUninstallConfirm(false)}
-                title="Uninstall Executor"
-                description="This will completely remove Executor, Roblox, and all associated data from your system. This action cannot be undone."
-                onConfirm={async () => {
-                    try {
-                        await uninstallApp();
-                        toast.success("Uninstalling Executor...");
-                    } catch (error) {
-                        toast.error("Failed to uninstall Executor");
-                        console.error("Failed to uninstall:", error);
-                        setShowUninstallConfirm(false);
-                    }
-                }}
-                confirmText="Uninstall"
-                confirmVariant="destructive"
-            >
-                <div className="rounded-lg border border-ctp-yellow/20 bg-ctp-yellow/5 p-3">
-                    <div className="flex items-start gap-2">
-                        <AlertTriangle
-                            size={16}
-                            className="mt-0.5 shrink-0 text-ctp-yellow"
-                        />
-                        <div className="space-y-1">
-                            <div className="text-xs font-medium text-ctp-yellow">
-                                Warning
-                            </div>
-                            <div className="text-xs text-ctp-subtext0">
-                                This will permanently delete all application
-                                data, executor files, Roblox installation,
-                                scripts, settings, tabs, workspaces, and
-                                execution history.
-                            </div>
-                        </div>
-                    </div>
-                </div>
-            </Modal>
+            />
         </>
     );
 };
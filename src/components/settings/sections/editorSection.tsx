@@ -1,7 +1,19 @@
-import { Code2, Monitor, MousePointer2, Sparkles, Type } from "lucide-react";
-import type { FC } from "react";
+import {
+    Code2,
+    History,
+    Monitor,
+    MousePointer2,
+    Sparkles,
+    Type,
+} from "lucide-react";
+import { type FC, useEffect, useState } from "react";
 import { useSettings } from "../../../hooks/core/useSettings";
+import {
+    loadSettings,
+    saveSettings,
+} from "../../../services/system/settingsService";
 import type { SettingsKey } from "../../../types/core/settings";
+import type { HistorySettings } from "../../../types/system/settings";
 import { Checkbox } from "../../ui/input/checkbox";
 import { RadioGroup } from "../../ui/input/radioGroup";
 import { Slider } from "../../ui/input/slider";
@@ -9,6 +21,24 @@ import { SettingGroup } from "../settingGroup";
 
 export const EditorSection: FC = () => {
     const { settings, updateSettings } = useSettings();
+    const [history, setHistory] = useState<HistorySettings | null>(null);
+
+    useEffect(() => {
+        loadSettings()
+            .then((stored) => setHistory(stored.history))
+            .catch((error) =>
+                console.error("Failed to load history settings:", error),
+            );
+    }, []);
+
+    const updateHistory = async (updates: Partial<HistorySettings>) => {
+        try {
+            const stored = await saveSettings({ history: updates });
+            setHistory(stored.history);
+        } catch (error) {
+            console.error("Failed to save history settings:", error);
+        }
+    };
 
     const handleSliderChange = (
         key: SettingsKey,
@@ -265,6 +295,37 @@ export const EditorSection: FC = () => {
                         description="Number of suggestions to show at once"
                     />
                 </SettingGroup>
+
+                {history && (
+                    <SettingGroup
+                        title="Tab History"
+                        description="Revisions kept each time a tab is saved"
+                        icon={<History size={14} className="text-accent" />}
+                    >
+                        <Slider
+                            value={history.max_revisions}
+                            onChange={(value) =>
+                                updateHistory({ max_revisions: value })
+                            }
+                            min={10}
+                            max={500}
+                            step={10}
+                            label="Maximum revisions"
+                            description="Older revisions of a tab are dropped past this count"
+                        />
+                        <Slider
+                            value={history.max_age_days}
+                            onChange={(value) =>
+                                updateHistory({ max_age_days: value })
+                            }
+                            min={0}
+                            max={365}
+                            unit=" days"
+                            label="Maximum age"
+                            description="Revisions older than this are dropped; 0 keeps them all"
+                        />
+                    </SettingGroup>
+                )}
             </div>
         </>
     );
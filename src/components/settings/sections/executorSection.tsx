@@ -0,0 +1,248 @@
+import { Network, RotateCcw, Save, Timer } from "lucide-react";
+import { type FC, useEffect, useState } from "react";
+import { toast } from "react-hot-toast";
+import {
+    getDiscoveryOptions,
+    getExecutorEndpoints,
+    getExecutorSettings,
+    setDiscoveryOptions,
+    updateExecutorSettings,
+} from "../../../services/roblox/connectionService";
+import type {
+    DiscoveryOptions,
+    ExecutorEndpoints,
+    ExecutorName,
+    ExecutorSettings,
+} from "../../../types/system/connection";
+import { Button } from "../../ui/input/button";
+import { Input } from "../../ui/input/input";
+import { Slider } from "../../ui/input/slider";
+import { SettingGroup } from "../settingGroup";
+
+const EXECUTORS: ExecutorName[] = ["Hydrogen", "MacSploit"];
+
+type EndpointDraft = { host: string; min_port: string; max_port: string };
+
+const toDraft = (
+    endpoints: ExecutorEndpoints,
+): Record<ExecutorName, EndpointDraft> => {
+    const draft = {} as Record<ExecutorName, EndpointDraft>;
+    for (const name of EXECUTORS) {
+        draft[name] = {
+            host: endpoints[name].host,
+            min_port: String(endpoints[name].min_port),
+            max_port: String(endpoints[name].max_port),
+        };
+    }
+    return draft;
+};
+
+export const ExecutorSection: FC = () => {
+    const [settings, setSettings] = useState<ExecutorSettings | null>(null);
+    const [endpoints, setEndpoints] = useState<ExecutorEndpoints | null>(null);
+    const [discovery, setDiscovery] = useState<DiscoveryOptions | null>(null);
+    const [drafts, setDrafts] = useState<Record<
+        ExecutorName,
+        EndpointDraft
+    > | null>(null);
+
+    useEffect(() => {
+        Promise.all([
+            getExecutorSettings(),
+            getExecutorEndpoints(),
+            getDiscoveryOptions(),
+        ])
+            .then(([settings, endpoints, discovery]) => {
+                setSettings(settings);
+                setEndpoints(endpoints);
+                setDiscovery(discovery);
+                setDrafts(toDraft(endpoints));
+            })
+            .catch((error) =>
+                console.error("Failed to load executor settings:", error),
+            );
+    }, []);
+
+    const saveSettings = async (next: ExecutorSettings) => {
+        try {
+            const updated = await updateExecutorSettings(next);
+            setSettings(next);
+            setEndpoints(updated);
+            setDrafts(toDraft(updated));
+            toast.success("Executor settings saved");
+        } catch (error) {
+            toast.error(String(error));
+        }
+    };
+
+    const handleSave = (name: ExecutorName) => {
+        if (!settings || !drafts) return;
+        const draft = drafts[name];
+        saveSettings({
+            ...settings,
+            overrides: {
+                ...settings.overrides,
+                [name]: {
+                    ...settings.overrides[name],
+                    host: draft.host.trim(),
+                    min_port: Number(draft.min_port),
+                    max_port: Number(draft.max_port),
+                },
+            },
+        });
+    };
+
+    const handleReset = (name: ExecutorName) => {
+        if (!settings) return;
+        const { [name]: _, ...overrides } = settings.overrides;
+        saveSettings({ ...settings, overrides });
+    };
+
+    const handleDiscoveryChange = async (
+        key: keyof DiscoveryOptions,
+        value: number,
+    ) => {
+        if (!discovery || !settings) return;
+        const next = { ...discovery, [key]: value };
+        try {
+            await setDiscoveryOptions(next);
+            setDiscovery(next);
+            setSettings({ ...settings, discovery: next });
+        } catch (error) {
+            toast.error(String(error));
+        }
+    };
+
+    const updateDraft = (
+        name: ExecutorName,
+        key: keyof EndpointDraft,
+        value: string,
+    ) => {
+        setDrafts((prev) =>
+            prev ? { ...prev, [name]: { ...prev[name], [key]: value } } : prev,
+        );
+    };
+
+    return (
+        <>
+            <div className="mb-4 space-y-2">
+                <h2 className="flex items-center gap-2 text-xl font-medium text-ctp-text">
+                    <Network size={20} className="text-accent" />
+                    Executor
+                </h2>
+                <p className="-mt-1 select-none text-sm text-ctp-subtext0">
+                    Configure where Comet looks for executors
+                </p>
+            </div>
+
+            <div className="space-y-6">
+                {settings &&
+                    endpoints &&
+                    drafts &&
+                    EXECUTORS.map((name) => (
+                        <SettingGroup
+                            key={name}
+                            title={name}
+                            description={`${endpoints[name].host}, ports ${endpoints[name].min_port}-${endpoints[name].max_port}`}
+                            icon={<Network size={14} className="text-accent" />}
+                        >
+                            <div className="grid grid-cols-3 gap-2">
+                                {(
+                                    [
+                                        ["host", "Host"],
+                                        ["min_port", "Minimum port"],
+                                        ["max_port", "Maximum port"],
+                                    ] as const
+                                ).map(([key, label]) => (
+                                    <div key={key}>
+                                        <label
+                                            htmlFor={`${name}-${key}`}
+                                            className="mb-1 block text-xs text-ctp-subtext0"
+                                        >
+                                            {label}
+                                        </label>
+                                        <Input
+                                            id={`${name}-${key}`}
+                                            value={drafts[name][key]}
+                                            inputMode={
+                                                key === "host"
+                                                    ? "text"
+                                                    : "numeric"
+                                            }
+                                            onChange={(e) =>
+                                                updateDraft(
+                                                    name,
+                                                    key,
+                                                    e.target.value,
+                                                )
+                                            }
+                                            className="h-8 w-full border-ctp-surface2 bg-ctp-surface1 text-xs text-ctp-text"
+                                        />
+                                    </div>
+                                ))}
+                            </div>
+                            <div className="flex justify-end gap-2">
+                                <Button
+                                    onClick={() => handleReset(name)}
+                                    disabled={!settings.overrides[name]}
+                                    className="flex h-7 items-center justify-center gap-1.5 rounded-lg border border-ctp-surface2 bg-ctp-surface1 px-3 text-xs font-medium text-ctp-subtext0 transition-colors hover:bg-white/10 disabled:opacity-50"
+                                >
+                                    <RotateCcw
+                                        size={14}
+                                        className="stroke-[2.5]"
+                                    />
+                                    Reset
+                                </Button>
+                                <Button
+                                    onClick={() => handleSave(name)}
+                                    className="flex h-7 items-center justify-center gap-1.5 rounded-lg border border-ctp-surface2 bg-accent px-3 text-xs font-medium text-white transition-colors hover:bg-accent/90"
+                                >
+                                    <Save size={14} className="stroke-[2.5]" />
+                                    Save
+                                </Button>
+                            </div>
+                        </SettingGroup>
+                    ))}
+
+                {discovery && (
+                    <SettingGroup
+                        title="Discovery"
+                        description="How long each port may take to answer during a scan"
+                        icon={<Timer size={14} className="text-accent" />}
+                    >
+                        <Slider
+                            value={discovery.connect_timeout_ms}
+                            onChange={(value) =>
+                                handleDiscoveryChange(
+                                    "connect_timeout_ms",
+                                    value,
+                                )
+                            }
+                            min={50}
+                            max={2000}
+                            step={50}
+                            label="Connect timeout"
+                            description="Time allowed to open a connection to a port"
+                            unit="ms"
+                        />
+                        <Slider
+                            value={discovery.handshake_timeout_ms}
+                            onChange={(value) =>
+                                handleDiscoveryChange(
+                                    "handshake_timeout_ms",
+                                    value,
+                                )
+                            }
+                            min={100}
+                            max={5000}
+                            step={100}
+                            label="Handshake timeout"
+                            description="Time allowed for an open port to identify as an executor"
+                            unit="ms"
+                        />
+                    </SettingGroup>
+                )}
+            </div>
+        </>
+    );
+};
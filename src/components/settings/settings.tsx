@@ -5,6 +5,7 @@ import { SETTINGS_SECTIONS } from "../../constants/core/settings";
 import { Header } from "../ui/header";
 import { ApplicationSection } from "./sections/applicationSection";
 import { EditorSection } from "./sections/editorSection";
+import { ExecutorSection } from "./sections/executorSection";
 import { InterfaceSection } from "./sections/interfaceSection";
 import { KeybindsSection } from "./sections/keybindsSection";
 import { TraySection } from "./sections/traySection";
@@ -23,6 +24,8 @@ export const Settings: FC = () => {
                 return <KeybindsSection />;
             case "application":
                 return <ApplicationSection />;
+            case "executor":
+                return <ExecutorSection />;
             case "tray":
                 return <TraySection />;
             default:
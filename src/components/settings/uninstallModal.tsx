@@ -0,0 +1,182 @@
+import { AlertTriangle } from "lucide-react";
+import { type FC, useEffect, useState } from "react";
+import { toast } from "react-hot-toast";
+import {
+    backupBeforeUninstall,
+    previewUninstall,
+    quitApp,
+    uninstallApp,
+} from "../../services/system/uninstallService";
+import type {
+    RemovalResult,
+    UninstallTarget,
+} from "../../types/system/uninstall";
+import { Checkbox } from "../ui/input/checkbox";
+import { Modal } from "../ui/modal";
+
+interface UninstallModalProps {
+    isOpen: boolean;
+    onClose: () => void;
+}
+
+const formatSize = (bytes: number) => {
+    const units = ["B", "KB", "MB", "GB"];
+    let size = bytes;
+    let unit = 0;
+    while (size >= 1024 && unit < units.length - 1) {
+        size /= 1024;
+        unit++;
+    }
+    return `${unit === 0 ? size : size.toFixed(1)} ${units[unit]}`;
+};
+
+const STATUS_LABELS: Record<RemovalResult["status"], string> = {
+    removed: "Removed",
+    not_found: "Not found",
+    failed: "Failed",
+};
+
+export const UninstallModal: FC<UninstallModalProps> = ({
+    isOpen,
+    onClose,
+}) => {
+    const [targets, setTargets] = useState<UninstallTarget[]>([]);
+    const [selected, setSelected] = useState<Set<string>>(new Set());
+    const [createBackup, setCreateBackup] = useState(true);
+    const [isRunning, setIsRunning] = useState(false);
+    const [report, setReport] = useState<RemovalResult[] | null>(null);
+
+    useEffect(() => {
+        if (!isOpen) return;
+        setReport(null);
+        previewUninstall()
+            .then((targets) => {
+                setTargets(targets);
+                setSelected(
+                    new Set(
+                        targets
+                            .filter((target) => target.exists)
+                            .map((target) => target.path),
+                    ),
+                );
+            })
+            .catch((error) => {
+                console.error("Failed to preview uninstall:", error);
+                toast.error("Failed to list files to uninstall");
+            });
+    }, [isOpen]);
+
+    const toggle = (path: string) => {
+        setSelected((current) => {
+            const next = new Set(current);
+            if (next.has(path)) {
+                next.delete(path);
+            } else {
+                next.add(path);
+            }
+            return next;
+        });
+    };
+
+    const handleUninstall = async () => {
+        if (isRunning || selected.size === 0) return;
+        setIsRunning(true);
+        try {
+            if (createBackup) {
+                const backup = await backupBeforeUninstall();
+                toast.success(`Backup saved to ${backup}`);
+            }
+            setReport(await uninstallApp([...selected]));
+        } catch (error) {
+            toast.error("Failed to uninstall Executor");
+            console.error("Failed to uninstall:", error);
+        } finally {
+            setIsRunning(false);
+        }
+    };
+
+    if (report) {
+        return (
+            <Modal
+                isOpen={isOpen}
+                onClose={onClose}
+                title="Uninstall Report"
+                onConfirm={quitApp}
+                confirmText="Quit Comet"
+            >
+                <div className="space-y-2">
+                    {report.map((result) => (
+                        <div key={result.path} className="text-xs">
+                            <div className="flex justify-between gap-2">
+                                <span className="truncate text-ctp-text">
+                                    {result.path}
+                                </span>
+                                <span
+                                    className={
+                                        result.status === "failed"
+                                            ? "shrink-0 text-ctp-red"
+                                            : "shrink-0 text-ctp-subtext0"
+                                    }
+                                >
+                                    {STATUS_LABELS[result.status]}
+                                </span>
+                            </div>
+                            {result.error && (
+                                <div className="text-ctp-red">
+                                    {result.error}
+                                </div>
+                            )}
+                        </div>
+                    ))}
+                </div>
+            </Modal>
+        );
+    }
+
+    return (
+        <Modal
+            isOpen={isOpen}
+            onClose={onClose}
+            title="Uninstall Executor"
+            description="Choose what to remove. Paths in Applications need your administrator password."
+            onConfirm={handleUninstall}
+            confirmText={isRunning ? "Uninstalling..." : "Uninstall"}
+            confirmVariant="destructive"
+        >
+            <div className="space-y-3">
+                <div className="max-h-64 overflow-y-auto">
+                    {targets.map((target) => (
+                        <Checkbox
+                            key={target.path}
+                            checked={selected.has(target.path)}
+                            onChange={() => toggle(target.path)}
+                            label={`${target.label} (${
+                                target.exists
+                                    ? formatSize(target.size_bytes)
+                                    : "not found"
+                            })`}
+                            description={target.path}
+                        />
+                    ))}
+                </div>
+                <Checkbox
+                    checked={createBackup}
+                    onChange={() => setCreateBackup(!createBackup)}
+                    label="Back up scripts and workspaces first"
+                    description="Saved as a .tar.gz in Documents/Comet Backups"
+                />
+                <div className="rounded-lg border border-ctp-yellow/20 bg-ctp-yellow/5 p-3">
+                    <div className="flex items-start gap-2">
+                        <AlertTriangle
+                            size={16}
+                            className="mt-0.5 shrink-0 text-ctp-yellow"
+                        />
+                        <div className="text-xs text-ctp-subtext0">
+                            Selected paths are permanently deleted.
+                        </div>
+                    </div>
+                </div>
+            </div>
+        </Modal>
+    );
+};
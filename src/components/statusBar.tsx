@@ -5,9 +5,11 @@ import { toast } from "react-hot-toast";
 import { Tooltip } from "react-tooltip";
 import { useEditor } from "../hooks/core/useEditor";
 import { useKeybinds } from "../hooks/core/useKeybinds";
+import { useConnection } from "../hooks/roblox/useConnection";
 import { useSidebar } from "../hooks/ui/useSidebar";
 import { useStatusBar } from "../hooks/ui/useStatusBar";
 import { beautifierService } from "../services/features/beautifierService";
+import type { ConnectionStatus } from "../types/system/connection";
 import type {
     EditorPosition,
     ErrorDropdownProps,
@@ -160,6 +162,39 @@ const ErrorDropdown: FC<ErrorDropdownProps> = ({
     );
 };
 
+const describeConnection = (status: ConnectionStatus) => {
+    if (status.is_connected) {
+        const latency = status.metrics.latency_ms;
+        return {
+            color: "bg-ctp-green",
+            label: `Port ${status.port}`,
+            tooltip:
+                latency === null
+                    ? "Connected"
+                    : `Connected, ${latency} ms latency`,
+        };
+    }
+    if (status.is_connecting) {
+        return {
+            color: "bg-ctp-yellow",
+            label: "Connecting",
+            tooltip: "Looking for the executor",
+        };
+    }
+    if (status.roblox_processes.length === 0) {
+        return {
+            color: "bg-ctp-red",
+            label: "Roblox closed",
+            tooltip: "Open Roblox to execute scripts",
+        };
+    }
+    return {
+        color: "bg-ctp-red",
+        label: "Not injected",
+        tooltip: "Roblox is running but the executor isn't attached yet",
+    };
+};
+
 export const StatusBar: FC = () => {
     const [position, setPosition] = useState<EditorPosition>({
         lineNumber: 1,
@@ -171,6 +206,7 @@ export const StatusBar: FC = () => {
     const { isVisible, toggleSidebar } = useSidebar();
     const { setIsWorkspaceSearchOpen, activeScreen } = useKeybinds();
     const { config, moveItem } = useStatusBar();
+    const { status: connection, refreshConnection } = useConnection();
     const previousTabRef = useRef(activeTab);
     const diagnosticsButtonRef = useRef<HTMLButtonElement>(null);
     const [draggedItem, setDraggedItem] = useState<string | null>(null);
@@ -257,6 +293,15 @@ export const StatusBar: FC = () => {
         }
     };
 
+    const handleConnectionClick = async () => {
+        try {
+            await refreshConnection();
+        } catch (error) {
+            toast.error("Failed to reconnect");
+            console.error("Failed to reconnect:", error);
+        }
+    };
+
     const handleDragStart = (e: React.DragEvent, itemId: string) => {
         e.stopPropagation();
         e.dataTransfer.effectAllowed = "move";
@@ -386,6 +431,27 @@ export const StatusBar: FC = () => {
                         )}
                     </div>
                 ) : null;
+            case "connection": {
+                if (!connection) return null;
+                const { color, label, tooltip } =
+                    describeConnection(connection);
+                return (
+                    <div {...dragProps}>
+                        <button
+                            type="button"
+                            onClick={handleConnectionClick}
+                            data-tooltip-id="status-bar-tooltip"
+                            data-tooltip-content={`${tooltip}. Click to reconnect`}
+                            className="flex items-center gap-1.5 rounded px-1 py-0.5 transition-colors hover:bg-ctp-surface0"
+                        >
+                            <span
+                                className={`inline-block h-1.5 w-1.5 rounded-full ${color}`}
+                            />
+                            <span>{label}</span>
+                        </button>
+                    </div>
+                );
+            }
             case "lines":
                 return activeScreen === "Editor" ? (
                     <div {...dragProps}>
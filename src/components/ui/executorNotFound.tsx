@@ -1,5 +1,4 @@
 import { listen } from "@tauri-apps/api/event";
-import { invoke } from "@tauri-apps/api/tauri";
 import {
     AlertTriangle,
     CheckCircle2,
@@ -11,6 +10,7 @@ import { motion } from "motion/react";
 import { type FC, useEffect, useState } from "react";
 import toast from "react-hot-toast";
 import { installExecutor } from "../../services/features/executorService";
+import { getBrand } from "../../services/system/brandService";
 import type { InstallProgress } from "../../types/ui/ui";
 
 const LoadingDots = () => {
@@ -39,16 +39,12 @@ export const ExecutorNotFound: FC = () => {
     const [isInstalling, setIsInstalling] = useState(false);
     const [installState, setInstallState] = useState<string>("");
     const [appName, setAppName] = useState<string>("");
-    const [installCommand, setInstallCommand] = useState<string>("");
+    const [installUrl, setInstallUrl] = useState<string>("");
 
     useEffect(() => {
-        invoke<string>("get_app_name").then((name) => {
-            setAppName(name);
-            const installUrl =
-                name.toLowerCase() === "ronix"
-                    ? "https://www.ronixmac.lol/install"
-                    : "https://www.hydrogen.lat/install";
-            setInstallCommand(`bash -c "$(curl -fsSL ${installUrl})"`);
+        getBrand().then((brand) => {
+            setAppName(brand.executor);
+            setInstallUrl(brand.installUrl);
         });
     }, []);
 
@@ -56,15 +52,27 @@ export const ExecutorNotFound: FC = () => {
         const unlisten = listen<InstallProgress>(
             "executor-progress",
             (event) => {
-                const { state } = event.payload;
+                const progress = event.payload;
+                const { state } = progress;
                 setInstallState(state);
 
                 const getStatusMessage = () => {
-                    switch (state) {
+                    switch (progress.state) {
+                        case "downloading":
+                            return progress.total
+                                ? `Downloading installer... ${Math.round(
+                                      (progress.downloaded / progress.total) *
+                                          100,
+                                  )}%`
+                                : `Downloading installer... ${Math.round(
+                                      progress.downloaded / 1024,
+                                  )} KB`;
+                        case "verifying":
+                            return "Verifying installer...";
                         case "installing":
                             return `Installing ${appName}...`;
                         case "error":
-                            return "Installation failed!";
+                            return `Installation failed: ${progress.message}`;
                         case "completed":
                             return "Installation complete!";
                         default:
@@ -97,7 +105,9 @@ export const ExecutorNotFound: FC = () => {
     }, [appName]);
 
     const handleCopy = async () => {
-        await navigator.clipboard.writeText(installCommand);
+        await navigator.clipboard.writeText(
+            `bash -c "$(curl -fsSL ${installUrl})"`,
+        );
         setCopied(true);
         setTimeout(() => setCopied(false), 2000);
     };
@@ -257,7 +267,11 @@ export const ExecutorNotFound: FC = () => {
                             >
                                 {installState === "preparing"
                                     ? "Preparing installation..."
-                                    : `Installing ${appName}...`}
+                                    : installState === "downloading"
+                                      ? "Downloading installer..."
+                                      : installState === "verifying"
+                                        ? "Verifying installer..."
+                                        : `Installing ${appName}...`}
                             </motion.div>
                         </motion.div>
                     )}
@@ -278,9 +292,7 @@ export const ExecutorNotFound: FC = () => {
                                 <span className="text-ctp-blue">curl</span>
                                 <span className="text-ctp-text"> -fsSL </span>
                                 <span className="text-ctp-yellow">
-                                    {appName.toLowerCase() === "ronix"
-                                        ? "https://www.ronixmac.lol/install"
-                                        : "https://www.hydrogen.lat/install"}
+                                    {installUrl}
                                 </span>
                                 <span className="text-ctp-green">)"</span>
                             </code>
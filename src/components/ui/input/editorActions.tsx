@@ -2,6 +2,7 @@ import {
     ChevronRight,
     ExternalLink,
     History,
+    Layers,
     Loader2,
     Play,
     Terminal,
@@ -9,6 +10,7 @@ import {
 import { motion } from "motion/react";
 import { type FC, useState } from "react";
 import { Tooltip } from "react-tooltip";
+import { useEditor } from "../../../hooks/core/useEditor";
 import { useLocalStorage } from "../../../hooks/core/useLocalStorage";
 import { useSettings } from "../../../hooks/core/useSettings";
 import { useExecute } from "../../../hooks/execution/useExecute";
@@ -18,6 +20,7 @@ import { useConsole } from "../../../hooks/ui/useConsole";
 import type { EditorActionsState } from "../../../types/core/editor";
 import type { ActionMenuProps } from "../../../types/core/workspace";
 import { ExecutionHistory } from "../executionHistory";
+import { InstancePicker } from "../instancePicker";
 
 const EDITOR_ACTIONS_STORAGE_KEY = "comet-editor-actions";
 
@@ -29,19 +32,21 @@ export const Actions: FC<
     const { executeScript } = useScript();
     const { isFloating } = useConsole();
     const { settings, updateSettings } = useSettings();
+    const { tabs, activeTab } = useEditor();
     const [{ isExpanded, isPinned }, setActionsState] =
         useLocalStorage<EditorActionsState>(EDITOR_ACTIONS_STORAGE_KEY, {
             isExpanded: false,
             isPinned: false,
         });
     const [showHistory, setShowHistory] = useState(false);
+    const [showInstances, setShowInstances] = useState(false);
 
     const containerVariants = {
         collapsed: {
             width: "44px",
         },
         expanded: {
-            width: "204px",
+            width: "240px",
         },
     };
 
@@ -72,6 +77,11 @@ export const Actions: FC<
         }
     };
 
+    const getScriptContent = () =>
+        getEditorContent
+            ? getEditorContent()
+            : (tabs.find((tab) => tab.id === activeTab)?.content ?? null);
+
     const toggleConsole = () => {
         updateSettings({
             interface: {
@@ -183,6 +193,17 @@ export const Actions: FC<
                                 />
                             </button>
 
+                            <button
+                                type="button"
+                                data-tooltip-id="editor-action-tooltip"
+                                data-tooltip-content="Execute On..."
+                                onClick={() => setShowInstances(true)}
+                                disabled={isExecuting}
+                                className="flex h-7 w-7 items-center justify-center rounded-lg border border-ctp-surface2 bg-ctp-surface1 text-accent transition-all hover:bg-ctp-surface2 active:scale-95 disabled:cursor-not-allowed disabled:opacity-50"
+                            >
+                                <Layers size={14} className="stroke-[2.5]" />
+                            </button>
+
                             <button
                                 type="button"
                                 data-tooltip-id="editor-action-tooltip"
@@ -230,6 +251,12 @@ export const Actions: FC<
                 onClose={() => setShowHistory(false)}
             />
 
+            <InstancePicker
+                isOpen={showInstances}
+                onClose={() => setShowInstances(false)}
+                getContent={getScriptContent}
+            />
+
             <Tooltip
                 id="editor-action-tooltip"
                 place="top"
@@ -0,0 +1,126 @@
+import { type FC, useEffect, useState } from "react";
+import { toast } from "react-hot-toast";
+import { executeScriptOn } from "../../services/execution/scriptService";
+import { listExecutorInstances } from "../../services/roblox/connectionService";
+import type {
+    ExecutionTarget,
+    ExecutorInstance,
+} from "../../types/system/connection";
+import { Modal } from "./modal";
+
+type InstancePickerProps = {
+    isOpen: boolean;
+    onClose: () => void;
+    getContent: () => string | null;
+};
+
+export const InstancePicker: FC<InstancePickerProps> = ({
+    isOpen,
+    onClose,
+    getContent,
+}) => {
+    const [instances, setInstances] = useState<ExecutorInstance[]>([]);
+    const [selected, setSelected] = useState<number[]>([]);
+    const [isScanning, setIsScanning] = useState(false);
+
+    useEffect(() => {
+        if (!isOpen) return;
+        setInstances([]);
+        setSelected([]);
+        setIsScanning(true);
+
+        listExecutorInstances()
+            .then((instances) => {
+                setInstances(instances);
+                setSelected(instances.map((instance) => instance.port));
+            })
+            .catch((error) => {
+                toast.error("Failed to find executor instances");
+                console.error(error);
+            })
+            .finally(() => setIsScanning(false));
+    }, [isOpen]);
+
+    const toggle = (port: number) => {
+        setSelected((prev) =>
+            prev.includes(port)
+                ? prev.filter((selectedPort) => selectedPort !== port)
+                : [...prev, port],
+        );
+    };
+
+    const handleExecute = async () => {
+        const content = getContent();
+        if (!content || selected.length === 0) return;
+
+        const target: ExecutionTarget =
+            selected.length === instances.length
+                ? { kind: "all" }
+                : selected.length === 1
+                  ? { kind: "port", port: selected[0] }
+                  : { kind: "ports", ports: selected };
+
+        try {
+            const results = await executeScriptOn(content, target);
+            const failed = results.filter((result) => !result.success);
+            if (failed.length === 0) {
+                toast.success(
+                    `Executed on ${results.length} instance${results.length === 1 ? "" : "s"}`,
+                );
+            } else {
+                for (const result of failed) {
+                    toast.error(
+                        `Port ${result.port}: ${result.error ?? "Execution failed"}`,
+                    );
+                }
+            }
+            onClose();
+        } catch (error) {
+            toast.error("Failed to execute script");
+            console.error(error);
+        }
+    };
+
+    return (
+        <Modal
+            isOpen={isOpen}
+            onClose={onClose}
+            title="Execute On"
+            description="Choose which executor instances receive the script."
+            onConfirm={handleExecute}
+            confirmText="Execute"
+        >
+            {isScanning ? (
+                <p className="text-xs text-ctp-subtext0">
+                    Scanning for instances...
+                </p>
+            ) : instances.length === 0 ? (
+                <p className="text-xs text-ctp-subtext0">
+                    No executor instances are running.
+                </p>
+            ) : (
+                <div className="max-h-48 overflow-y-auto rounded-lg border border-ctp-surface2">
+                    {instances.map((instance) => (
+                        <label
+                            key={instance.port}
+                            className="flex w-full cursor-pointer items-center justify-between px-3 py-1.5 text-xs text-ctp-text transition-colors hover:bg-ctp-surface1"
+                        >
+                            <span className="flex items-center gap-2">
+                                <input
+                                    type="checkbox"
+                                    checked={selected.includes(instance.port)}
+                                    onChange={() => toggle(instance.port)}
+                                    className="accent-accent"
+                                />
+                                Port {instance.port}
+                            </span>
+                            <span className="text-ctp-subtext0">
+                                {instance.api_type}
+                            </span>
+                        </label>
+                    ))}
+                </div>
+            )}
+        </Modal>
+    );
+};
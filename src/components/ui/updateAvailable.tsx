@@ -0,0 +1,99 @@
+import { type FC, useEffect, useState } from "react";
+import { toast } from "react-hot-toast";
+import {
+    checkForUpdates,
+    downloadUpdate,
+    onUpdateAvailable,
+    onUpdateProgress,
+    skipUpdate,
+} from "../../services/system/updateService";
+import type { UpdateInfo } from "../../types/system/update";
+import type { InstallProgress } from "../../types/ui/ui";
+import { Modal } from "./modal";
+
+const describeProgress = (progress: InstallProgress | null) => {
+    switch (progress?.state) {
+        case "downloading":
+            return progress.total
+                ? `Downloading ${Math.round((progress.downloaded / progress.total) * 100)}%`
+                : "Downloading...";
+        case "verifying":
+            return "Verifying...";
+        case "preparing":
+            return "Preparing...";
+        default:
+            return "Download";
+    }
+};
+
+export const UpdateAvailable: FC = () => {
+    const [update, setUpdate] = useState<UpdateInfo | null>(null);
+    const [progress, setProgress] = useState<InstallProgress | null>(null);
+    const [isDownloading, setIsDownloading] = useState(false);
+
+    useEffect(() => {
+        checkForUpdates()
+            .then(setUpdate)
+            .catch((error) => console.error(error));
+
+        const unlistenUpdate = onUpdateAvailable(setUpdate);
+        const unlistenProgress = onUpdateProgress(setProgress);
+        return () => {
+            unlistenUpdate.then((fn) => fn());
+            unlistenProgress.then((fn) => fn());
+        };
+    }, []);
+
+    if (!update) return null;
+
+    const handleDownload = async () => {
+        if (isDownloading) return;
+        setIsDownloading(true);
+        try {
+            await downloadUpdate(update.version);
+            toast.success(`Comet ${update.version} downloaded`);
+            setUpdate(null);
+        } catch (error) {
+            toast.error("Failed to download update");
+            console.error(error);
+        } finally {
+            setIsDownloading(false);
+            setProgress(null);
+        }
+    };
+
+    const handleSkip = async () => {
+        try {
+            await skipUpdate(update.version);
+        } catch (error) {
+            console.error(error);
+        }
+        setUpdate(null);
+    };
+
+    return (
+        <Modal
+            isOpen
+            onClose={() => setUpdate(null)}
+            title={`Comet ${update.version} is available`}
+            description={`You have ${update.current_version}.`}
+            onConfirm={handleDownload}
+            confirmText={describeProgress(isDownloading ? progress : null)}
+            footer={
+                <button
+                    type="button"
+                    onClick={handleSkip}
+                    className="flex h-8 items-center justify-center rounded-lg border border-ctp-surface2 bg-ctp-surface1 px-3 text-xs font-medium text-ctp-subtext0 transition-colors hover:bg-ctp-surface2"
+                >
+                    Skip this version
+                </button>
+            }
+        >
+            {update.notes && (
+                <div className="max-h-64 overflow-y-auto whitespace-pre-wrap text-xs text-ctp-subtext0">
+                    {update.notes}
+                </div>
+            )}
+        </Modal>
+    );
+};
@@ -4,6 +4,7 @@ import {
     Download,
     Edit2,
     FileCode,
+    History,
     Plus,
     X,
 } from "lucide-react";
@@ -14,6 +15,7 @@ import { useWorkspace } from "../../hooks/core/useWorkspace";
 import { exportTab } from "../../services/core/tabService";
 import type { TabbarProps } from "../../types/core/workspace";
 import { ContextMenu } from "../ui/input/contextMenu";
+import { TabHistoryModal } from "./tabHistoryModal";
 import { WorkspaceSelector } from "./workspaceSelector";
 
 export const Tabbar: FC<TabbarProps> = ({
@@ -43,6 +45,7 @@ export const Tabbar: FC<TabbarProps> = ({
     const [editingTab, setEditingTab] = useState<string | null>(null);
     const [editValue, setEditValue] = useState("");
     const [showDropdown, setShowDropdown] = useState(false);
+    const [historyTabId, setHistoryTabId] = useState<string | null>(null);
     const inputRef = useRef<HTMLInputElement>(null);
     const scrollContainerRef = useRef<HTMLDivElement>(null);
     const dropdownRef = useRef<HTMLDivElement>(null);
@@ -139,6 +142,11 @@ export const Tabbar: FC<TabbarProps> = ({
                 icon: <Copy size={14} className="stroke-[2.5]" />,
                 onClick: () => duplicateTab(tabId),
             },
+            {
+                label: "History",
+                icon: <History size={14} className="stroke-[2.5]" />,
+                onClick: () => setHistoryTabId(tabId),
+            },
             {
                 label: "Export",
                 icon: <Download size={14} className="stroke-[2.5]" />,
@@ -386,6 +394,11 @@ export const Tabbar: FC<TabbarProps> = ({
                     position={contextMenu}
                     onClose={() => setContextMenu(null)}
                 />
+                <TabHistoryModal
+                    tab={tabs.find((tab) => tab.id === historyTabId) ?? null}
+                    workspaceId={activeWorkspace}
+                    onClose={() => setHistoryTabId(null)}
+                />
             </div>
         );
     }
@@ -522,6 +535,11 @@ export const Tabbar: FC<TabbarProps> = ({
                 position={contextMenu}
                 onClose={() => setContextMenu(null)}
             />
+            <TabHistoryModal
+                tab={tabs.find((tab) => tab.id === historyTabId) ?? null}
+                workspaceId={activeWorkspace}
+                onClose={() => setHistoryTabId(null)}
+            />
         </div>
     );
 };
@@ -0,0 +1,152 @@
+import { type FC, useEffect, useState } from "react";
+import { toast } from "react-hot-toast";
+import { useEditor } from "../../hooks/core/useEditor";
+import {
+    diffTabRevisions,
+    listTabRevisions,
+    restoreTabRevision,
+} from "../../services/core/revisionService";
+import type { Tab } from "../../types/core/editor";
+import type { DiffLine, Revision } from "../../types/core/revisions";
+import { Modal } from "../ui/modal";
+
+type TabHistoryModalProps = {
+    tab: Tab | null;
+    workspaceId: string | null;
+    onClose: () => void;
+};
+
+const formatSize = (bytes: number) =>
+    bytes < 1024 ? `${bytes} B` : `${(bytes / 1024).toFixed(1)} KB`;
+
+const lineClass = (kind: DiffLine["kind"]) => {
+    switch (kind) {
+        case "added":
+            return "bg-green-500/10 text-ctp-green";
+        case "removed":
+            return "bg-red-500/10 text-ctp-red";
+        default:
+            return "text-ctp-subtext0";
+    }
+};
+
+const linePrefix = (kind: DiffLine["kind"]) => {
+    switch (kind) {
+        case "added":
+            return "+";
+        case "removed":
+            return "-";
+        default:
+            return " ";
+    }
+};
+
+export const TabHistoryModal: FC<TabHistoryModalProps> = ({
+    tab,
+    workspaceId,
+    onClose,
+}) => {
+    const { updateTab } = useEditor();
+    const [revisions, setRevisions] = useState<Revision[]>([]);
+    const [selected, setSelected] = useState<number | null>(null);
+    const [diff, setDiff] = useState<DiffLine[]>([]);
+    // Keyed on the id so edits to the open tab don't reload its history.
+    const tabId = tab?.id ?? null;
+
+    useEffect(() => {
+        setRevisions([]);
+        setSelected(null);
+        if (!tabId || !workspaceId) return;
+
+        listTabRevisions(workspaceId, tabId)
+            .then((revisions) => {
+                setRevisions(revisions);
+                setSelected(revisions[1]?.id ?? revisions[0]?.id ?? null);
+            })
+            .catch((error) => {
+                toast.error("Failed to load tab history");
+                console.error(error);
+            });
+    }, [tabId, workspaceId]);
+
+    useEffect(() => {
+        setDiff([]);
+        const newest = revisions[0];
+        if (!tabId || !workspaceId || !newest || selected === null) return;
+
+        diffTabRevisions(workspaceId, tabId, selected, newest.id)
+            .then(setDiff)
+            .catch((error) => console.error(error));
+    }, [tabId, workspaceId, revisions, selected]);
+
+    const handleRestore = async () => {
+        if (!tab || !workspaceId || selected === null) return;
+        try {
+            const restored = await restoreTabRevision(
+                workspaceId,
+                tab.id,
+                selected,
+            );
+            await updateTab(tab.id, { content: restored.content });
+            toast.success("Revision restored");
+            onClose();
+        } catch (error) {
+            toast.error("Failed to restore revision");
+            console.error(error);
+        }
+    };
+
+    return (
+        <Modal
+            isOpen={tab !== null}
+            onClose={onClose}
+            title={`History of ${tab?.title ?? ""}`}
+            description="Changes from the selected revision to the latest save."
+            onConfirm={handleRestore}
+            confirmText="Restore"
+        >
+            {revisions.length === 0 ? (
+                <p className="text-xs text-ctp-subtext0">
+                    No revisions saved yet.
+                </p>
+            ) : (
+                <div className="flex flex-col gap-3">
+                    <div className="max-h-32 overflow-y-auto rounded-lg border border-ctp-surface2">
+                        {revisions.map((revision, index) => (
+                            <button
+                                type="button"
+                                key={revision.id}
+                                onClick={() => setSelected(revision.id)}
+                                className={`flex w-full items-center justify-between px-3 py-1.5 text-left text-xs transition-colors ${
+                                    selected === revision.id
+                                        ? "bg-accent/10 text-accent"
+                                        : "text-ctp-text hover:bg-ctp-surface1"
+                                }`}
+                            >
+                                <span>
+                                    {new Date(
+                                        revision.created_at,
+                                    ).toLocaleString()}
+                                    {index === 0 && " (latest)"}
+                                </span>
+                                <span className="text-ctp-subtext0">
+                                    {formatSize(revision.size)}
+                                </span>
+                            </button>
+                        ))}
+                    </div>
+                    <pre className="max-h-64 overflow-auto rounded-lg border border-ctp-surface2 bg-ctp-mantle py-1 font-mono text-[11px]">
+                        {diff.map((line, index) => (
+                            <div
+                                key={`${index}-${line.old_line}-${line.new_line}`}
+                                className={`px-2 ${lineClass(line.kind)}`}
+                            >
+                                {linePrefix(line.kind)} {line.content}
+                            </div>
+                        ))}
+                    </pre>
+                </div>
+            )}
+        </Modal>
+    );
+};
@@ -2,6 +2,7 @@ import {
     Code2,
     Keyboard,
     MenuSquare,
+    Network,
     Settings2,
     SettingsIcon,
 } from "lucide-react";
@@ -28,6 +29,12 @@ export const SETTINGS_SECTIONS: SettingsSection[] = [
         description: "Customize hotkeys",
         icon: Keyboard,
     },
+    {
+        id: "executor",
+        title: "Executor",
+        description: "Ports and discovery",
+        icon: Network,
+    },
     {
         id: "tray",
         title: "Tray menu",
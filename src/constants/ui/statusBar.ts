@@ -6,6 +6,7 @@ export const STATUS_BAR_DEFAULT_CONFIG: StatusBarConfig = {
         { id: "search", group: "left" },
         { id: "beautify", group: "left" },
         { id: "diagnostics", group: "right" },
+        { id: "connection", group: "right" },
         { id: "lines", group: "right" },
         { id: "position", group: "right" },
     ],
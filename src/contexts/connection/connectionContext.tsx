@@ -0,0 +1,72 @@
+import {
+    type FC,
+    type ReactNode,
+    useCallback,
+    useEffect,
+    useState,
+} from "react";
+import { toast } from "react-hot-toast";
+import {
+    getConnectionStatus,
+    incrementPort as incrementPortService,
+    onConnectionUpdate,
+    onRobloxExited,
+    onRobloxLaunched,
+    refreshConnection as refreshConnectionService,
+} from "../../services/roblox/connectionService";
+import type { ConnectionStatus } from "../../types/system/connection";
+import { ConnectionContext } from "./connectionContextType";
+
+export const ConnectionProvider: FC<{ children: ReactNode }> = ({
+    children,
+}) => {
+    const [status, setStatus] = useState<ConnectionStatus | null>(null);
+
+    useEffect(() => {
+        getConnectionStatus()
+            .then(setStatus)
+            .catch(() => {});
+
+        const unlisten = onConnectionUpdate(setStatus);
+
+        // A new player is the moment the executor may appear, so look right
+        // away instead of waiting out the supervisor's backoff.
+        const unlistenLaunched = onRobloxLaunched(() => {
+            toast("Roblox launched, looking for the executor", {
+                id: "roblox-process-toast",
+            });
+            refreshConnectionService()
+                .then(setStatus)
+                .catch(() => {});
+        });
+        const unlistenExited = onRobloxExited(() => {
+            toast("Roblox closed", { id: "roblox-process-toast" });
+        });
+
+        return () => {
+            unlisten.then((fn) => fn());
+            unlistenLaunched.then((fn) => fn());
+            unlistenExited.then((fn) => fn());
+        };
+    }, []);
+
+    const refreshConnection = useCallback(async () => {
+        setStatus(await refreshConnectionService());
+    }, []);
+
+    const incrementPort = useCallback(async () => {
+        setStatus(await incrementPortService());
+    }, []);
+
+    const value = {
+        status,
+        refreshConnection,
+        incrementPort,
+    };
+
+    return (
+        <ConnectionContext.Provider value={value}>
+            {children}
+        </ConnectionContext.Provider>
+    );
+};
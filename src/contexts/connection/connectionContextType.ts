@@ -0,0 +1,5 @@
+import React from "react";
+import type { ConnectionContextType } from "../../types/system/connection";
+
+export const ConnectionContext =
+    React.createContext<ConnectionContextType | null>(null);
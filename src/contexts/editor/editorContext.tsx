@@ -271,7 +271,7 @@ export const EditorProvider: FC<{ children: ReactNode }> = ({ children }) => {
             try {
                 const tab = tabs.find((t) => t.id === id);
                 if (tab) {
-                    await deleteTab(activeWorkspace, tab.title);
+                    await deleteTab(activeWorkspace, tab.id);
                     clearEditorState(activeWorkspace, id);
                     setTabs((prev) => prev.filter((tab) => tab.id !== id));
                     if (activeTab === id) {
@@ -306,11 +306,7 @@ export const EditorProvider: FC<{ children: ReactNode }> = ({ children }) => {
                 };
 
                 if (updates.title && updates.title !== currentTab.title) {
-                    await renameTab(
-                        activeWorkspace,
-                        currentTab.title,
-                        updates.title,
-                    );
+                    await renameTab(activeWorkspace, id, updates.title);
 
                     setTabs((prev) => {
                         const tabIndex = prev.findIndex((tab) => tab.id === id);
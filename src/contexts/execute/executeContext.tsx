@@ -32,7 +32,6 @@ export const ExecuteProvider = ({
             setIsExecuting(true);
             const result = await executeScript(script);
 
-            localStorage.setItem("lastExecutedScript", script);
             try {
                 await invoke("save_last_script", { script });
             } catch (error) {
@@ -4,8 +4,12 @@ import {
     DEFAULT_EDITOR_SETTINGS,
     SETTINGS_STORAGE_KEY,
 } from "../../constants/core/settings";
-import { useLocalStorage } from "../../hooks/core/useLocalStorage";
 import { getApiType } from "../../services/roblox/connectionService";
+import {
+    loadSettings,
+    onSettingsChanged,
+    saveSettings,
+} from "../../services/system/settingsService";
 import type { EditorSettings } from "../../types/core/settings";
 import { SettingsContext } from "./settingsContextType";
 
@@ -81,38 +85,72 @@ export const SettingsProvider = ({
 }: {
     children: React.ReactNode;
 }) => {
-    const [settings, setSettings] = useLocalStorage<EditorSettings>(
-        SETTINGS_STORAGE_KEY,
+    const [settings, setSettings] = useState<EditorSettings>(
         DEFAULT_EDITOR_SETTINGS,
     );
     const [isInitialized, setIsInitialized] = useState(false);
     const [hasLocalStorageError] = useState(false);
 
-    // biome-ignore lint/correctness/useExhaustiveDependencies: <>
     useEffect(() => {
-        try {
-            const savedValue = localStorage.getItem(SETTINGS_STORAGE_KEY);
-            if (!savedValue) return;
-
-            const parsed = JSON.parse(savedValue);
-            const merged = mergeWithDefaults(parsed, DEFAULT_EDITOR_SETTINGS);
+        const restore = async () => {
+            try {
+                const stored = await loadSettings();
+                // Editor settings used to live in localStorage; move them over once.
+                const legacyValue = localStorage.getItem(SETTINGS_STORAGE_KEY);
+                const saved =
+                    stored.editor ??
+                    (legacyValue ? JSON.parse(legacyValue) : null);
+                if (!saved) return;
+
+                const merged = mergeWithDefaults(saved, DEFAULT_EDITOR_SETTINGS);
+
+                if (!validateSettings(merged)) {
+                    toast.error("Settings were corrupted. Restored to defaults.");
+                    setSettings(DEFAULT_EDITOR_SETTINGS);
+                    return;
+                }
 
-            if (!validateSettings(merged)) {
-                toast.error("Settings were corrupted. Restored to defaults.");
+                setSettings(merged);
+                if (!stored.editor) {
+                    await saveSettings({ editor: merged });
+                    localStorage.removeItem(SETTINGS_STORAGE_KEY);
+                }
+            } catch (error) {
+                console.error("Failed to load saved settings:", error);
+                toast.error("Failed to load saved settings. Restoring defaults.");
                 setSettings(DEFAULT_EDITOR_SETTINGS);
-                return;
+            } finally {
+                setIsInitialized(true);
             }
+        };
 
-            setSettings(merged);
-        } catch (error) {
-            console.error("Failed to parse saved settings:", error);
-            toast.error("Failed to load saved settings. Restoring defaults.");
-            setSettings(DEFAULT_EDITOR_SETTINGS);
-        } finally {
-            setIsInitialized(true);
-        }
+        restore();
+
+        const unlisten = onSettingsChanged((stored) => {
+            if (!stored.editor) return;
+            const merged = mergeWithDefaults(
+                stored.editor,
+                DEFAULT_EDITOR_SETTINGS,
+            );
+            if (validateSettings(merged)) {
+                setSettings(merged);
+            }
+        });
+        return () => {
+            unlisten.then((fn) => fn());
+        };
     }, []);
 
+    // The backend ignores saves that change nothing, so echoes from other windows stop here.
+    useEffect(() => {
+        if (!isInitialized) return;
+
+        saveSettings({ editor: settings }).catch((error) => {
+            console.error("Failed to save settings:", error);
+            toast.error("Failed to save settings");
+        });
+    }, [settings, isInitialized]);
+
     useEffect(() => {
         if (!isInitialized) return;
 
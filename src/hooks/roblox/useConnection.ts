@@ -0,0 +1,13 @@
+import { useContext } from "react";
+import { ConnectionContext } from "../../contexts/connection/connectionContextType";
+
+/**
+ * Hook for accessing the executor connection status, kept current by backend events
+ * Must be used within a ConnectionProvider component
+ */
+export const useConnection = () => {
+    const context = useContext(ConnectionContext);
+    if (!context)
+        throw new Error("useConnection must be used within ConnectionProvider");
+    return context;
+};
@@ -15,7 +15,13 @@ export const useStatusBar = () => {
         const savedConfig = localStorage.getItem("status-bar-config");
         if (savedConfig) {
             try {
-                setConfig(JSON.parse(savedConfig));
+                const saved: StatusBarConfig = JSON.parse(savedConfig);
+                // Items added since the order was saved go at the end.
+                const added = STATUS_BAR_DEFAULT_CONFIG.order.filter(
+                    (item) =>
+                        !saved.order.some((known) => known.id === item.id),
+                );
+                setConfig({ ...saved, order: [...saved.order, ...added] });
             } catch {
                 setConfig(STATUS_BAR_DEFAULT_CONFIG);
             }
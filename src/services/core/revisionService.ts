@@ -0,0 +1,58 @@
+import { invoke } from "@tauri-apps/api/tauri";
+import type { Tab } from "../../types/core/editor";
+import type { DiffLine, Revision } from "../../types/core/revisions";
+
+/**
+ * Lists the saved revisions of a tab
+ * @param workspaceId The ID of the workspace containing the tab
+ * @param tabId The ID of the tab
+ * @returns Promise with the revisions, newest first
+ */
+export const listTabRevisions = async (
+    workspaceId: string,
+    tabId: string,
+): Promise<Revision[]> => {
+    return invoke<Revision[]>("list_tab_revisions", { workspaceId, tabId });
+};
+
+/**
+ * Computes the line diff between two revisions of a tab
+ * @param workspaceId The ID of the workspace containing the tab
+ * @param tabId The ID of the tab
+ * @param from The older revision
+ * @param to The newer revision
+ * @returns Promise with every line of both revisions, marked as unchanged, added or removed
+ */
+export const diffTabRevisions = async (
+    workspaceId: string,
+    tabId: string,
+    from: number,
+    to: number,
+): Promise<DiffLine[]> => {
+    return invoke<DiffLine[]>("diff_tab_revisions", {
+        workspaceId,
+        tabId,
+        from,
+        to,
+    });
+};
+
+/**
+ * Puts the content of a revision back into its tab
+ * @param workspaceId The ID of the workspace containing the tab
+ * @param tabId The ID of the tab
+ * @param revision The revision to restore
+ * @returns Promise with the restored tab
+ * @throws Error if the tab or revision no longer exists
+ */
+export const restoreTabRevision = async (
+    workspaceId: string,
+    tabId: string,
+    revision: number,
+): Promise<Tab> => {
+    return invoke<Tab>("restore_tab_revision", {
+        workspaceId,
+        tabId,
+        revision,
+    });
+};
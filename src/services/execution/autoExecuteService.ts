@@ -1,7 +1,6 @@
 import { invoke } from "@tauri-apps/api/tauri";
 import type { AutoExecuteFile } from "../../types/execution/autoExecute";
-import { SETTINGS_STORAGE_KEY } from "../../constants/core/settings";
-import type { EditorSettings } from "../../types/core/settings";
+import { loadSettings } from "../system/settingsService";
 
 /**
  * Convert lowercase API type to PascalCase for Rust enum
@@ -13,19 +12,15 @@ const toPascalCase = (apiType: string): string => {
 };
 
 /**
- * Helper to get the current API type from localStorage
+ * Helper to get the API type chosen in the editor settings
  * Returns PascalCase string for Rust backend (Hydrogen, MacSploit)
  */
-const getCurrentApiType = (): string => {
+const getCurrentApiType = async (): Promise<string> => {
     try {
-        const savedValue = localStorage.getItem(SETTINGS_STORAGE_KEY);
-        if (savedValue) {
-            const parsed = JSON.parse(savedValue) as Partial<EditorSettings>;
-            const apiType = parsed.app?.apiType ?? "hydrogen";
-            return toPascalCase(apiType);
-        }
+        const settings = await loadSettings();
+        return toPascalCase(settings.editor?.app?.apiType ?? "hydrogen");
     } catch (error) {
-        console.error("Failed to get API type from localStorage:", error);
+        console.error("Failed to get API type from settings:", error);
     }
     return "Hydrogen";
 };
@@ -49,7 +44,7 @@ export const saveAutoExecuteFile = async (
     name: string,
     content: string,
 ): Promise<void> => {
-    const apiType = getCurrentApiType();
+    const apiType = await getCurrentApiType();
     return invoke("save_auto_execute_file", { name, content, apiType });
 };
 
@@ -59,7 +54,7 @@ export const saveAutoExecuteFile = async (
  * @throws Error if deleting file fails
  */
 export const deleteAutoExecuteFile = async (name: string): Promise<void> => {
-    const apiType = getCurrentApiType();
+    const apiType = await getCurrentApiType();
     return invoke("delete_auto_execute_file", { name, apiType });
 };
 
@@ -73,7 +68,7 @@ export const renameAutoExecuteFile = async (
     oldName: string,
     newName: string,
 ): Promise<void> => {
-    const apiType = getCurrentApiType();
+    const apiType = await getCurrentApiType();
     return invoke("rename_auto_execute_file", { oldName, newName, apiType });
 };
 
@@ -82,7 +77,7 @@ export const renameAutoExecuteFile = async (
  * @throws Error if opening directory fails
  */
 export const openAutoExecuteDirectory = async (): Promise<void> => {
-    const apiType = getCurrentApiType();
+    const apiType = await getCurrentApiType();
     return invoke("open_auto_execute_directory", { apiType });
 };
 
@@ -101,6 +96,6 @@ export const isAutoExecuteEnabled = async (): Promise<boolean> => {
  * @throws Error if toggling state fails
  */
 export const toggleAutoExecute = async (): Promise<boolean> => {
-    const apiType = getCurrentApiType();
+    const apiType = await getCurrentApiType();
     return invoke("toggle_auto_execute", { apiType });
 };
@@ -1,6 +1,13 @@
 import { invoke } from "@tauri-apps/api/tauri";
 import type { Tab } from "../../types/core/editor";
-import type { ScriptExecutionResult } from "../../types/execution/script";
+import type {
+    ExecutionResult,
+    ScriptExecutionResult,
+} from "../../types/execution/script";
+import type {
+    ExecutionTarget,
+    InstanceResult,
+} from "../../types/system/connection";
 
 /**
  * Executes a Lua script through the Executor API
@@ -17,9 +24,19 @@ export const executeScript = async (
 
         try {
             console.log("[executeScript] Calling send_script...");
-            const result = await invoke<boolean>("send_script", { script });
+            const result = await invoke<ExecutionResult>("send_script", {
+                script,
+            });
             console.log("[executeScript] send_script result:", result);
-            return { success: result, content: script };
+            return {
+                success: result.success,
+                error: result.success
+                    ? undefined
+                    : result.response ||
+                      `Executor responded with status ${result.status}`,
+                content: script,
+                result,
+            };
         } catch (execError) {
             const errorMessage =
                 execError instanceof Error
@@ -48,6 +65,29 @@ export const executeScript = async (
     }
 };
 
+/**
+ * Executes a Lua script on one, several or all executor instances
+ * @param script The Lua script to execute
+ * @param target The instances that should receive the script
+ * @returns Promise that resolves to one result per targeted instance
+ * @throws Error if the execution request fails
+ */
+export const executeScriptOn = async (
+    script: string,
+    target: ExecutionTarget,
+): Promise<InstanceResult[]> => {
+    try {
+        await invoke("save_last_script", { script });
+        return await invoke<InstanceResult[]>("send_script_to", {
+            script,
+            target,
+        });
+    } catch (error) {
+        console.error("Failed to execute script on instances:", error);
+        throw error;
+    }
+};
+
 /**
  * Saves a script to the filesystem
  * @param path The path where to save the script
@@ -151,15 +191,15 @@ export const getTabState = async (
 /**
  * Deletes a tab from the workspace
  * @param workspaceId The ID of the workspace
- * @param title The title of the tab to delete
+ * @param tabId The ID of the tab to delete
  * @throws Error if the delete operation fails
  */
 export const deleteTab = async (
     workspaceId: string,
-    title: string,
+    tabId: string,
 ): Promise<void> => {
     try {
-        await invoke("delete_tab", { workspaceId, title });
+        await invoke("delete_tab", { workspaceId, tabId });
     } catch (error) {
         console.error("Failed to delete tab:", error);
         throw error;
@@ -169,17 +209,17 @@ export const deleteTab = async (
 /**
  * Renames a tab in the workspace
  * @param workspaceId The ID of the workspace
- * @param oldTitle The current title of the tab
+ * @param tabId The ID of the tab to rename
  * @param newTitle The new title for the tab
  * @throws Error if the rename operation fails
  */
 export const renameTab = async (
     workspaceId: string,
-    oldTitle: string,
+    tabId: string,
     newTitle: string,
 ): Promise<void> => {
     try {
-        await invoke("rename_tab", { workspaceId, oldTitle, newTitle });
+        await invoke("rename_tab", { workspaceId, tabId, newTitle });
     } catch (error) {
         console.error("Failed to rename tab:", error);
         throw error;
@@ -1,4 +1,6 @@
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/tauri";
+import type { DetectionReport } from "../../types/system/connection";
 
 /**
  * Checks if executor is installed on the system
@@ -26,3 +28,44 @@ export const installExecutor = async (): Promise<void> => {
         throw error;
     }
 };
+
+/**
+ * Reads the installer's step log for support requests
+ * @returns Promise that resolves to the log contents
+ * @throws Error if the log cannot be read
+ */
+export const getInstallLog = async (): Promise<string> => {
+    try {
+        return await invoke<string>("get_install_log");
+    } catch (error) {
+        console.error("Failed to read install log:", error);
+        throw error;
+    }
+};
+
+/**
+ * Gets every executor found on disk and the one Comet recommends using
+ * @returns Promise that resolves to the detection report
+ * @throws Error if detection fails
+ */
+export const getDetectionReport = async (): Promise<DetectionReport> => {
+    try {
+        return await invoke<DetectionReport>("get_detection_report");
+    } catch (error) {
+        console.error("Failed to detect executors:", error);
+        throw error;
+    }
+};
+
+/**
+ * Subscribes to executors being installed into or removed from Roblox
+ * @param callback Function called with the new detection report
+ * @returns Promise that resolves to a function removing the listener
+ */
+export const onDetectionChanged = (
+    callback: (report: DetectionReport) => void,
+): Promise<UnlistenFn> => {
+    return listen<DetectionReport>("detection-changed", (event) =>
+        callback(event.payload),
+    );
+};
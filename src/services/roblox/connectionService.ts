@@ -1,5 +1,13 @@
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/tauri";
-import type { ConnectionStatus } from "../../types/system/connection";
+import type {
+    ConnectionStatus,
+    DiscoveryOptions,
+    ExecutorEndpoints,
+    ExecutorInstance,
+    ExecutorSettings,
+    RobloxProcess,
+} from "../../types/system/connection";
 
 /**
  * Gets the current API type from the backend
@@ -57,3 +65,131 @@ export const incrementPort = async (): Promise<ConnectionStatus> => {
         throw error;
     }
 };
+
+/**
+ * Lists every executor instance currently answering on its port range
+ * @returns Promise that resolves to the live executor instances
+ * @throws Error if the scan fails
+ */
+export const listExecutorInstances = async (): Promise<ExecutorInstance[]> => {
+    try {
+        return await invoke<ExecutorInstance[]>("list_executor_instances");
+    } catch (error) {
+        console.error("Failed to list executor instances:", error);
+        throw error;
+    }
+};
+
+/**
+ * Gets the per-port timeouts used when scanning for executors
+ * @returns Promise that resolves to the current discovery options
+ * @throws Error if fetching the options fails
+ */
+export const getDiscoveryOptions = async (): Promise<DiscoveryOptions> => {
+    try {
+        return await invoke<DiscoveryOptions>("get_discovery_options");
+    } catch (error) {
+        console.error("Failed to get discovery options:", error);
+        throw error;
+    }
+};
+
+/**
+ * Updates the per-port timeouts used when scanning for executors
+ * @param options The new connect and handshake timeouts in milliseconds
+ * @throws Error if the options are invalid
+ */
+export const setDiscoveryOptions = async (
+    options: DiscoveryOptions,
+): Promise<void> => {
+    try {
+        await invoke("set_discovery_options", { options });
+    } catch (error) {
+        console.error("Failed to set discovery options:", error);
+        throw error;
+    }
+};
+
+/**
+ * Gets the persisted per-executor endpoint overrides
+ * @returns Promise that resolves to the saved executor settings
+ * @throws Error if fetching the settings fails
+ */
+export const getExecutorSettings = async (): Promise<ExecutorSettings> => {
+    try {
+        return await invoke<ExecutorSettings>("get_executor_settings");
+    } catch (error) {
+        console.error("Failed to get executor settings:", error);
+        throw error;
+    }
+};
+
+/**
+ * Gets the host, port range and handshake currently used for each executor
+ * @returns Promise that resolves to the effective endpoints
+ * @throws Error if fetching the endpoints fails
+ */
+export const getExecutorEndpoints = async (): Promise<ExecutorEndpoints> => {
+    try {
+        return await invoke<ExecutorEndpoints>("get_executor_endpoints");
+    } catch (error) {
+        console.error("Failed to get executor endpoints:", error);
+        throw error;
+    }
+};
+
+/**
+ * Validates, saves and applies new executor endpoint overrides and discovery timeouts
+ * @param settings The settings to persist; omitted overrides keep their defaults
+ * @returns Promise that resolves to the effective endpoints after the update
+ * @throws Error if the settings are invalid or cannot be saved
+ */
+export const updateExecutorSettings = async (
+    settings: ExecutorSettings,
+): Promise<ExecutorEndpoints> => {
+    try {
+        return await invoke<ExecutorEndpoints>("update_executor_settings", {
+            settings,
+        });
+    } catch (error) {
+        console.error("Failed to update executor settings:", error);
+        throw error;
+    }
+};
+
+/**
+ * Subscribes to connection state transitions reported by the backend
+ * @param callback Function called with the new connection status
+ * @returns Promise that resolves to a function removing the listener
+ */
+export const onConnectionUpdate = (
+    callback: (status: ConnectionStatus) => void,
+): Promise<UnlistenFn> => {
+    return listen<ConnectionStatus>("connection-update", (event) =>
+        callback(event.payload),
+    );
+};
+
+/**
+ * Subscribes to Roblox player processes starting
+ * @param callback Function called with the launched process
+ * @returns Promise that resolves to a function removing the listener
+ */
+export const onRobloxLaunched = (
+    callback: (process: RobloxProcess) => void,
+): Promise<UnlistenFn> => {
+    return listen<RobloxProcess>("roblox-launched", (event) =>
+        callback(event.payload),
+    );
+};
+
+/**
+ * Subscribes to Roblox player processes exiting
+ * @param callback Function called with the PID of the exited process
+ * @returns Promise that resolves to a function removing the listener
+ */
+export const onRobloxExited = (
+    callback: (pid: number) => void,
+): Promise<UnlistenFn> => {
+    return listen<number>("roblox-exited", (event) => callback(event.payload));
+};
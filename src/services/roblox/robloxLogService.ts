@@ -1,9 +1,14 @@
 import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/tauri";
-import type { LogLevel, LogLine } from "../../types/roblox/robloxConsole";
+import type {
+    ExecutorOutput,
+    LogLevel,
+    LogLine,
+} from "../../types/roblox/robloxConsole";
 
 const listeners = new Set<(log: LogLine) => void>();
 let unlistenCallback: UnlistenFn | undefined;
+let unlistenExecutorOutput: UnlistenFn | undefined;
 let isWatching = false;
 
 /**
@@ -49,6 +54,27 @@ const parseLogLine = (rawLine: string): LogLine => {
     };
 };
 
+/**
+ * Converts executor output into a LogLine tied to the script that produced it
+ * @param output The decoded executor output message
+ * @returns Structured LogLine object carrying the originating script
+ */
+const parseExecutorOutput = (output: ExecutorOutput): LogLine => {
+    const level: LogLevel = output.kind === "error" ? "ERROR" : "INFO";
+
+    return {
+        timestamp: new Date(output.timestamp).toISOString(),
+        level,
+        message: output.message,
+        raw: output.message,
+        source: {
+            executionId: output.execution_id,
+            scriptPreview: output.script_preview,
+            port: output.port,
+        },
+    };
+};
+
 /**
  * Starts watching for Roblox log updates
  * Initializes the log watcher and sets up event listeners
@@ -71,6 +97,13 @@ export const startWatching = async (): Promise<void> => {
                 notifyListeners(parsedLog);
             },
         );
+
+        unlistenExecutorOutput = await listen(
+            "executor-output",
+            (event: { payload: ExecutorOutput }) => {
+                notifyListeners(parseExecutorOutput(event.payload));
+            },
+        );
     } catch (error) {
         isWatching = false;
         throw new Error(
@@ -94,6 +127,7 @@ export const stopWatching = async (): Promise<void> => {
     try {
         await invoke("stop_log_watcher");
         await unlistenCallback?.();
+        await unlistenExecutorOutput?.();
         isWatching = false;
     } catch (error) {
         throw new Error(
@@ -0,0 +1,10 @@
+import { invoke } from "@tauri-apps/api/tauri";
+import type { Brand } from "../../types/system/brand";
+
+/**
+ * Gets the branding of this build: product and executor names, install URL and paths.
+ * @returns The brand selected from the bundled config by the app's product name
+ */
+export const getBrand = async (): Promise<Brand> => {
+    return invoke<Brand>("get_brand");
+};
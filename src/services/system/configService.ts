@@ -0,0 +1,22 @@
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/tauri";
+import type { LoadedConfig } from "../../types/system/config";
+
+/**
+ * Gets the merged config: compiled-in defaults, the bundled file and the user's `config.json`.
+ * @returns The config and any layers that were skipped as invalid
+ */
+export const getConfig = async (): Promise<LoadedConfig> => {
+    return invoke<LoadedConfig>("get_config");
+};
+
+/**
+ * Listens for the config being reloaded after one of its files changed.
+ */
+export const onConfigChanged = (
+    callback: (config: LoadedConfig) => void,
+): Promise<UnlistenFn> => {
+    return listen<LoadedConfig>("config-changed", (event) =>
+        callback(event.payload),
+    );
+};
@@ -0,0 +1,31 @@
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/tauri";
+import type { Settings, SettingsPatch } from "../../types/system/settings";
+
+/**
+ * Gets the settings stored by the backend, upgraded to the current schema version
+ */
+export const loadSettings = async (): Promise<Settings> => {
+    return invoke<Settings>("get_settings");
+};
+
+/**
+ * Merges a partial update into the stored settings and notifies every window
+ * @param patch The sections and values to change
+ * @returns The settings after the change
+ * @throws Error if a value has the wrong type
+ */
+export const saveSettings = async (patch: SettingsPatch): Promise<Settings> => {
+    return invoke<Settings>("set_settings", { patch });
+};
+
+/**
+ * Listens for the settings being changed by any window or by the backend itself.
+ */
+export const onSettingsChanged = (
+    callback: (settings: Settings) => void,
+): Promise<UnlistenFn> => {
+    return listen<Settings>("settings-changed", (event) =>
+        callback(event.payload),
+    );
+};
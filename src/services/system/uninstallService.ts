@@ -1,13 +1,50 @@
 import { invoke } from "@tauri-apps/api/tauri";
+import type {
+    RemovalResult,
+    UninstallTarget,
+} from "../../types/system/uninstall";
 
 /**
- * The `uninstallApp` function uses Tauri API to uninstall the app asynchronously and throws an error
- * if the operation fails.
+ * Lists every path the uninstaller can remove, with its size and whether it exists.
+ * Nothing is deleted.
  */
-export const uninstallApp = async (): Promise<void> => {
+export const previewUninstall = async (): Promise<UninstallTarget[]> => {
     try {
-        await invoke("uninstall_app");
+        return await invoke<UninstallTarget[]>("preview_uninstall");
+    } catch (error) {
+        throw new Error(`Failed to preview uninstall: ${error}`);
+    }
+};
+
+/**
+ * Archives scripts, auto-execute and workspace data into a `.tar.gz` and returns its path.
+ */
+export const backupBeforeUninstall = async (): Promise<string> => {
+    try {
+        return await invoke<string>("backup_before_uninstall");
+    } catch (error) {
+        throw new Error(`Failed to back up data: ${error}`);
+    }
+};
+
+/**
+ * The `uninstallApp` function removes the selected paths from the preview and returns what
+ * happened to each of them.
+ * @param paths - Paths taken from `previewUninstall`
+ */
+export const uninstallApp = async (
+    paths: string[],
+): Promise<RemovalResult[]> => {
+    try {
+        return await invoke<RemovalResult[]>("uninstall_app", { paths });
     } catch (error) {
         throw new Error(`Failed to uninstall app: ${error}`);
     }
 };
+
+/**
+ * Quits Comet, used once the uninstall report has been read.
+ */
+export const quitApp = async (): Promise<void> => {
+    await invoke("quit_app");
+};
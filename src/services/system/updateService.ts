@@ -0,0 +1,63 @@
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/tauri";
+import type { UpdateInfo } from "../../types/system/update";
+import type { InstallProgress } from "../../types/ui/ui";
+
+/**
+ * Checks the release manifest for a Comet version newer than the running one.
+ * @returns The update, or null when up to date or the latest version was skipped
+ */
+export const checkForUpdates = async (): Promise<UpdateInfo | null> => {
+    try {
+        return await invoke<UpdateInfo | null>("check_for_updates");
+    } catch (error) {
+        throw new Error(`Failed to check for updates: ${error}`);
+    }
+};
+
+/**
+ * Downloads and checksum-verifies an update, then opens it for installing.
+ * Progress is reported through `onUpdateProgress`.
+ * @param version - The version from `checkForUpdates`
+ * @returns Path of the downloaded file
+ */
+export const downloadUpdate = async (version: string): Promise<string> => {
+    try {
+        return await invoke<string>("download_update", { version });
+    } catch (error) {
+        throw new Error(`Failed to download update: ${error}`);
+    }
+};
+
+/**
+ * Stops notifying about `version`. Later versions are still reported.
+ */
+export const skipUpdate = async (version: string): Promise<void> => {
+    try {
+        await invoke("skip_update", { version });
+    } catch (error) {
+        throw new Error(`Failed to skip update: ${error}`);
+    }
+};
+
+/**
+ * Listens for updates found by the periodic background check.
+ */
+export const onUpdateAvailable = (
+    callback: (update: UpdateInfo) => void,
+): Promise<UnlistenFn> => {
+    return listen<UpdateInfo>("update-available", (event) =>
+        callback(event.payload),
+    );
+};
+
+/**
+ * Listens for download progress of an update.
+ */
+export const onUpdateProgress = (
+    callback: (progress: InstallProgress) => void,
+): Promise<UnlistenFn> => {
+    return listen<InstallProgress>("update-progress", (event) =>
+        callback(event.payload),
+    );
+};
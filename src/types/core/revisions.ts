@@ -0,0 +1,17 @@
+export interface Revision {
+    id: number;
+    /** Unix milliseconds */
+    created_at: number;
+    /** The tab's title when the revision was saved */
+    title: string;
+    /** Content size in bytes, before compression */
+    size: number;
+    sha256: string;
+}
+
+export interface DiffLine {
+    kind: "unchanged" | "added" | "removed";
+    old_line: number | null;
+    new_line: number | null;
+    content: string;
+}
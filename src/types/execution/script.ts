@@ -4,10 +4,21 @@ export interface ScriptExecutionOptions {
     toastId?: string;
 }
 
+export interface ExecutionResult {
+    success: boolean;
+    status: number | null;
+    response: string | null;
+    port: number;
+    api_type: "Hydrogen" | "MacSploit";
+    elapsed_ms: number;
+    execution_id: string | null;
+}
+
 export type ScriptExecutionResult = {
     success: boolean;
     error?: string;
     content: string;
+    result?: ExecutionResult;
 };
 
 export interface ScriptTab {
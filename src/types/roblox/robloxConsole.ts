@@ -2,11 +2,29 @@ import type { ConsoleSize } from "../ui/console";
 
 export type LogLevel = "INFO" | "WARN" | "ERROR" | "DEBUG";
 
+export interface LogSource {
+    executionId: string | null;
+    scriptPreview: string | null;
+    port: number;
+}
+
 export interface LogLine {
     timestamp: string;
     level: LogLevel;
     message: string;
     raw: string;
+    source?: LogSource;
+}
+
+export type ExecutorOutputKind = "print" | "error" | "unknown";
+
+export interface ExecutorOutput {
+    port: number;
+    kind: ExecutorOutputKind;
+    message: string;
+    execution_id: string | null;
+    script_preview: string | null;
+    timestamp: number;
 }
 
 export interface RobloxConsoleProps {
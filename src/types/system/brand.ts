@@ -0,0 +1,25 @@
+export interface BrandNotifications {
+    title: string;
+    disconnect: {
+        body: string;
+    };
+}
+
+export interface BrandConfig {
+    productName: string;
+    iconPath: string;
+    executor: string;
+    installUrl: string;
+    dylibPath: string;
+    dataFolder: string;
+    keyFile: string;
+    /** Absent for executors whose keys Comet can't validate */
+    keyValidationUrl?: string;
+    /** Absent until releases publish a manifest for this brand */
+    updateManifest?: string;
+    notifications: BrandNotifications;
+}
+
+export interface Brand extends BrandConfig {
+    id: string;
+}
@@ -0,0 +1,11 @@
+import type { BrandConfig } from "./brand";
+
+export interface CometConfig {
+    brands: Record<string, BrandConfig>;
+}
+
+export interface LoadedConfig {
+    config: CometConfig;
+    /** Config layers that were skipped because they didn't parse or validate */
+    errors: string[];
+}
@@ -1,15 +1,93 @@
+import type { ExecutionResult } from "../execution/script";
+
 export type ApiType = "hydrogen" | "macsploit";
 
+export interface ConnectionMetrics {
+    latency_ms: number | null;
+    connected_since: number | null;
+    uptime_ms: number;
+    reconnect_count: number;
+    failed_attempts: number;
+    next_retry_ms: number | null;
+}
+
+export interface RobloxProcess {
+    pid: number;
+    started_at: number;
+    cpu_percent: number;
+    memory_bytes: number;
+}
+
 export interface ConnectionStatus {
     is_connected: boolean;
     port: number | null;
     current_port: number;
     is_connecting: boolean;
     api_type: ApiType;
+    metrics: ConnectionMetrics;
+    roblox_processes: RobloxProcess[];
+}
+
+export interface ExecutorInstance {
+    port: number;
+    api_type: ApiType;
+}
+
+export type ExecutionTarget =
+    | { kind: "all" }
+    | { kind: "port"; port: number }
+    | { kind: "ports"; ports: number[] };
+
+export interface InstanceResult {
+    port: number;
+    success: boolean;
+    error: string | null;
+    result: ExecutionResult | null;
+}
+
+export interface DiscoveryOptions {
+    connect_timeout_ms: number;
+    handshake_timeout_ms: number;
+}
+
+export type ExecutorName = "Hydrogen" | "MacSploit";
+
+export interface EndpointConfig {
+    host: string;
+    min_port: number;
+    max_port: number;
+    secret_path: string | null;
+    secret_token: string | null;
+}
+
+export type EndpointOverride = Partial<EndpointConfig>;
+
+export interface ExecutorSettings {
+    overrides: Partial<Record<ExecutorName, EndpointOverride>>;
+    discovery: DiscoveryOptions;
+}
+
+export type ExecutorEndpoints = Record<ExecutorName, EndpointConfig>;
+
+export interface DetectedExecutor {
+    api_type: ExecutorName;
+    name: string;
+    install_paths: string[];
+    version: string | null;
+    injected: boolean;
+}
+
+export interface DetectionReport {
+    roblox_installed: boolean;
+    roblox_version: string | null;
+    executors: DetectedExecutor[];
+    recommended: ExecutorName;
+    conflict: boolean;
 }
 
 export interface ConnectionContextType {
-    status: ConnectionStatus;
+    /** Null until the first status arrives from the backend */
+    status: ConnectionStatus | null;
     refreshConnection: () => Promise<void>;
     incrementPort: () => Promise<void>;
 }
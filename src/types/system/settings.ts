@@ -0,0 +1,39 @@
+import type { EditorSettings } from "../core/settings";
+import type { ExecutorSettings } from "./connection";
+import type { TrayConfig } from "./tray";
+
+export interface Settings {
+    schema_version: number;
+    /** Null until the editor first saves its settings */
+    editor: EditorSettings | null;
+    tray: TrayConfig;
+    auto_execute: {
+        enabled: boolean;
+    };
+    workspace: {
+        active_workspace: string | null;
+    };
+    history: HistorySettings;
+    executor: ExecutorSettings;
+    updates: {
+        /** The release the user chose not to be told about again */
+        skipped_version: string | null;
+    };
+}
+
+/** How many saved revisions of each tab are kept */
+export interface HistorySettings {
+    max_revisions: number;
+    /** 0 keeps revisions regardless of age */
+    max_age_days: number;
+}
+
+/** Any subset of the settings except the schema version, merged key by key into the stored ones */
+export type SettingsPatch = {
+    [Section in Exclude<
+        keyof Settings,
+        "schema_version"
+    >]?: Settings[Section] extends object | null
+        ? Partial<NonNullable<Settings[Section]>>
+        : Settings[Section];
+};
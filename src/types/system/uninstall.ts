@@ -0,0 +1,15 @@
+export interface UninstallTarget {
+    label: string;
+    path: string;
+    exists: boolean;
+    size_bytes: number;
+    requires_admin: boolean;
+}
+
+export type RemovalStatus = "removed" | "not_found" | "failed";
+
+export interface RemovalResult {
+    path: string;
+    status: RemovalStatus;
+    error?: string;
+}
@@ -0,0 +1,6 @@
+export interface UpdateInfo {
+    current_version: string;
+    version: string;
+    notes: string;
+    pub_date: string | null;
+}
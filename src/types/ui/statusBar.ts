@@ -17,6 +17,7 @@ export type StatusBarItemType =
     | "search"
     | "beautify"
     | "diagnostics"
+    | "connection"
     | "lines"
     | "position";
 
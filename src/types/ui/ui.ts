@@ -41,9 +41,13 @@ export interface HeaderProps {
     actions?: ReactNode;
 }
 
-export interface InstallProgress {
-    state: string;
-}
+export type InstallProgress =
+    | { state: "preparing" }
+    | { state: "downloading"; downloaded: number; total: number | null }
+    | { state: "verifying" }
+    | { state: "installing" }
+    | { state: "completed" }
+    | { state: "error"; message: string };
 
 export interface ModalProps {
     isOpen: boolean;